@@ -3,6 +3,7 @@
 import { TOOLS, ToolDefinition, ExecuteToolResult } from "@/lib/tools";
 import { getAuditLog, updateAuditLog, getUserAuditLogs } from "@/lib/audit";
 import { replan } from "@/lib/planner";
+import { resolvePlaceholders } from "@/lib/placeholders";
 import { AuditLog } from "@/lib/types";
 
 export async function executeToolWithContext(
@@ -15,12 +16,29 @@ export async function executeToolWithContext(
     throw new Error(`Tool ${tool_name} not found`);
   }
 
+  // Substitute {{step_N.result...}} references with outputs of earlier steps
+  const priorLog = await getAuditLog(context.audit_log_id);
+  const stepOutputs = Object.fromEntries(
+    (priorLog?.steps || [])
+      .filter(s => s.status === "executed")
+      .map(s => [s.step_index, s.output])
+  );
+  const resolution = resolvePlaceholders(parameters, stepOutputs);
+  parameters = resolution.parameters;
+
   const startTime = Date.now();
   let result: any;
   let attempts = 0;
   const maxRetries = 3;
 
-  while (attempts < maxRetries) {
+  if (resolution.unresolved.length > 0) {
+    result = {
+      success: false,
+      error: `Invalid parameters: unresolved step references ${resolution.unresolved.join(", ")}`
+    };
+  }
+
+  while (resolution.unresolved.length === 0 && attempts < maxRetries) {
     try {
       result = await toolDef.execute(parameters);
       
@@ -1,4 +1,5 @@
-import { getAuditLog } from "@/lib/audit";
+import { getAuditLog, deleteAuditLog } from "@/lib/audit";
+import { authorizeUserDataRequest } from "@/lib/user-data";
 import { NextRequest, NextResponse } from "next/server";
 
 export const runtime = "edge";
@@ -26,3 +27,32 @@ export async function GET(
     return NextResponse.json({ error: "Failed to fetch audit log" }, { status: 500 });
   }
 }
+
+export async function DELETE(
+  req: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  const { id } = await params;
+  const { userId, error, status } = await authorizeUserDataRequest(req);
+
+  if (!userId) {
+    return NextResponse.json({ error }, { status });
+  }
+
+  if (!id) {
+    return NextResponse.json({ error: "Missing audit ID" }, { status: 400 });
+  }
+
+  try {
+    const deleted = await deleteAuditLog(userId, id);
+
+    if (!deleted) {
+      return NextResponse.json({ error: "Audit log not found" }, { status: 404 });
+    }
+
+    return NextResponse.json({ deleted: true });
+  } catch (error: any) {
+    console.error(`Error deleting audit log ${id}:`, error);
+    return NextResponse.json({ error: "Failed to delete audit log" }, { status: 500 });
+  }
+}
@@ -8,6 +8,7 @@ import { inferIntent } from "@/lib/intent";
 import { Redis } from "@upstash/redis";
 import { getUserPreferences, updateUserPreferences } from "@/lib/preferences";
 import { redis } from "@/lib/redis-client";
+import { isValidTimeZone } from "@/lib/date-utils";
 
 export const runtime = "edge";
 export const maxDuration = 30;
@@ -23,6 +24,8 @@ const ChatRequestSchema = z.object({
     lat: z.number().min(-90).max(90),
     lng: z.number().min(-180).max(180),
   }).nullable().optional(),
+  // The user's IANA time zone, so "tomorrow at 3pm" means their 3pm
+  timezone: z.string().refine(isValidTimeZone, "Invalid timezone").optional(),
 });
 
 export async function POST(req: Request) {
@@ -37,7 +40,7 @@ export async function POST(req: Request) {
       });
     }
 
-    const { messages, userLocation } = validatedBody.data;
+    const { messages, userLocation, timezone } = validatedBody.data;
 
     if (messages.length === 0) {
       return new Response("No messages provided", { status: 400 });
@@ -120,7 +123,7 @@ export async function POST(req: Request) {
     try {
       const intentStart = Date.now();
       const { avoidTools } = await getPlanWithAvoidance(userText, userIp);
-      const inferenceResult = await inferIntent(userText, avoidTools);
+      const inferenceResult = await inferIntent(userText, avoidTools, [], {}, { timeZone: timezone });
       intentInferenceLatency = Date.now() - intentStart;
       intent = inferenceResult.hypotheses.primary;
       rawModelResponse = inferenceResult.rawResponse;
@@ -239,11 +242,12 @@ export async function POST(req: Request) {
             location: z.string().optional(),
             restaurant_name: z.string().optional(),
             restaurant_address: z.string().optional(),
+            recurrence: z.string().optional(),
           })),
         }),
         execute: async (params: any) => {
           console.log("Executing add_calendar_event", params);
-          const result = await executeToolWithContext("add_calendar_event", params, {
+          const result = await executeToolWithContext("add_calendar_event", { ...params, timezone }, {
             audit_log_id: auditLog.id,
             step_index: auditLog.steps.length
           });
@@ -1,6 +1,6 @@
 import { NextRequest, NextResponse } from 'next/server';
 import { z } from 'zod';
-import { parseNaturalLanguageDate } from '@/lib/date-utils';
+import { parseNaturalLanguageDate, isValidTimeZone } from '@/lib/date-utils';
 
 const DownloadIcsSchema = z.object({
   title: z.string().default('Event'),
@@ -8,6 +8,7 @@ const DownloadIcsSchema = z.object({
   end: z.string().optional().nullable(),
   location: z.string().optional().default(''),
   description: z.string().optional().default(''),
+  recurrence: z.string().optional(),
 });
 
 function formatICalDate(date: Date): string {
@@ -17,6 +18,11 @@ function formatICalDate(date: Date): string {
 export async function GET(req: NextRequest) {
   const { searchParams } = new URL(req.url);
   const eventsParam = searchParams.get('events');
+  // Relative times like "tomorrow at 7pm" are read on the user's clock
+  const timeZone = searchParams.get('timezone') || undefined;
+  if (timeZone && !isValidTimeZone(timeZone)) {
+    return NextResponse.json({ error: "Invalid timezone" }, { status: 400 });
+  }
   
   let events: any[] = [];
 
@@ -38,6 +44,7 @@ export async function GET(req: NextRequest) {
       end: validatedParams.data.end,
       location: validatedParams.data.location,
       description: validatedParams.data.description,
+      recurrence: validatedParams.data.recurrence,
     }];
   }
 
@@ -48,8 +55,8 @@ export async function GET(req: NextRequest) {
   ];
 
   for (const event of events) {
-    const startDate = await parseNaturalLanguageDate(event.start);
-    let endDate = event.end ? await parseNaturalLanguageDate(event.end) : new Date(startDate.getTime() + 60 * 60 * 1000);
+    const startDate = await parseNaturalLanguageDate(event.start, timeZone);
+    let endDate = event.end ? await parseNaturalLanguageDate(event.end, timeZone) : new Date(startDate.getTime() + 60 * 60 * 1000);
 
     if (isNaN(endDate.getTime()) || endDate <= startDate) {
       endDate = new Date(startDate.getTime() + 60 * 60 * 1000);
@@ -62,8 +69,14 @@ export async function GET(req: NextRequest) {
       `DTEND:${formatICalDate(endDate)}`,
       `LOCATION:${event.location || ''}`,
       `DESCRIPTION:${(event.description || '').replace(/\n/g, '\\n')}`,
-      'END:VEVENT'
     );
+
+    // Only well-formed RRULE values are emitted so a bad rule can't corrupt the calendar file
+    if (typeof event.recurrence === 'string' && /^FREQ=[A-Z]+(;[A-Z]+=[A-Z0-9,+-]+)*$/.test(event.recurrence)) {
+      icsLines.push(`RRULE:${event.recurrence}`);
+    }
+
+    icsLines.push('END:VEVENT');
   }
 
   icsLines.push('END:VCALENDAR');
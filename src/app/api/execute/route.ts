@@ -117,7 +117,8 @@ function createToolExecutorForExecution(
     execute: async (
       toolName: string,
       parameters: Record<string, unknown>,
-      timeoutMs: number
+      timeoutMs: number,
+      signal?: AbortSignal
     ) => {
       const result = await registry.execute(
         toolName,
@@ -127,6 +128,7 @@ function createToolExecutorForExecution(
           stepId: "unknown",
           timeoutMs,
           startTime: performance.now(),
+          abortSignal: signal,
         },
         undefined // Use latest version
       );
@@ -136,6 +138,7 @@ function createToolExecutorForExecution(
         output: result.output,
         error: result.error,
         latency_ms: result.latency_ms,
+        retry_after_ms: result.retry_after_ms,
       };
     },
   };
@@ -355,6 +358,9 @@ async function orchestrateExecution(
               entry.error as string,
               entry.latency_ms
             );
+          } else {
+            // Plan-level events such as state_changed carry no step
+            tracer.addEntry(entry);
           }
         },
       });
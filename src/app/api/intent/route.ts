@@ -4,14 +4,22 @@ import { generatePlan } from "@/lib/planner";
 import { createAuditLog } from "@/lib/audit";
 import { getPlanWithAvoidance } from "@/app/actions";
 import { getMemoryClient } from "@/lib/engine/memory";
+import { getIntentJsonSchema } from "@/lib/engine/schema-utils";
+import { isValidTimeZone } from "@/lib/date-utils";
 import { z } from "zod";
 
 export const runtime = "edge";
 
 const IntentRequestSchema = z.object({
   text: z.string().min(1),
+  // The user's IANA time zone, so "tomorrow at 3pm" means their 3pm
+  timezone: z.string().refine(isValidTimeZone, "Invalid timezone").optional(),
 });
 
+export async function GET() {
+  return NextResponse.json(getIntentJsonSchema());
+}
+
 export async function POST(req: NextRequest) {
   try {
     const rawBody = await req.json();
@@ -24,7 +32,7 @@ export async function POST(req: NextRequest) {
       }, { status: 400 });
     }
 
-    const { text } = validatedBody.data;
+    const { text, timezone } = validatedBody.data;
     const userId = req.headers.get("x-forwarded-for") || "anonymous";
 
     try {
@@ -37,7 +45,7 @@ export async function POST(req: NextRequest) {
         .map(s => s.intent)
         .filter((i): i is any => i !== undefined);
 
-      const { hypotheses, rawResponse } = await inferIntent(text, avoidTools, history);
+      const { hypotheses, rawResponse } = await inferIntent(text, avoidTools, history, {}, { timeZone: timezone });
       const intent = hypotheses.primary;
       
       let plan = null;
@@ -0,0 +1,32 @@
+import { NextRequest, NextResponse } from "next/server";
+import { exportUserData, eraseUserData, authorizeUserDataRequest } from "@/lib/user-data";
+
+export const runtime = "edge";
+
+export async function GET(req: NextRequest) {
+  const { userId, error, status } = await authorizeUserDataRequest(req);
+  if (!userId) {
+    return NextResponse.json({ error }, { status });
+  }
+
+  try {
+    const data = await exportUserData(userId);
+    return NextResponse.json(data);
+  } catch (error: any) {
+    return NextResponse.json({ error: error.message }, { status: 500 });
+  }
+}
+
+export async function DELETE(req: NextRequest) {
+  const { userId, error, status } = await authorizeUserDataRequest(req);
+  if (!userId) {
+    return NextResponse.json({ error }, { status });
+  }
+
+  try {
+    const result = await eraseUserData(userId);
+    return NextResponse.json(result);
+  } catch (error: any) {
+    return NextResponse.json({ error: error.message }, { status: 500 });
+  }
+}
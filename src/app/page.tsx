@@ -12,6 +12,8 @@ export default function Home() {
   const [error, setError] = useState<string | null>(null);
   const [auditLogs, setAuditLogs] = useState<any[]>([]);
   const [showAudit, setShowAudit] = useState(false);
+  // Sent with each request so relative times are resolved on the user's clock
+  const timezone = Intl.DateTimeFormat().resolvedOptions().timeZone;
 
   useEffect(() => {
     if (navigator.geolocation) {
@@ -66,7 +68,7 @@ export default function Home() {
 
     setError(null);
     try {
-      await sendMessage({ text: input }, { body: { userLocation } });
+      await sendMessage({ text: input }, { body: { userLocation, timezone } });
       setInput("");
     } catch (err: any) {
       setError(err.message || "Failed to send message");
@@ -79,7 +81,7 @@ export default function Home() {
       if (lastUserMessage) {
         setError(null);
         sendMessage({ text: (lastUserMessage.parts.find(p => p.type === 'text') as any)?.text || "" }, {
-          body: { userLocation }
+          body: { userLocation, timezone }
         });
       }
     }
@@ -205,7 +207,7 @@ export default function Home() {
                                         onClick={() => {
                                           const time = "7 PM";
                                           sendMessage({ text: `I've selected ${r.name} at ${r.address}. Please add this to my calendar for tonight at ${time}.` }, {
-                                            body: { userLocation }
+                                            body: { userLocation, timezone }
                                           });
                                         }}
                                         className="w-full py-1.5 bg-blue-50 text-blue-600 rounded text-xs font-bold group-hover:bg-blue-600 group-hover:text-white transition-colors"
@@ -280,7 +282,7 @@ export default function Home() {
                                 sendMessage({ 
                                   text: `The tool "${toolName}" failed with error: "${toolInvocation.errorText}". Please retry the operation with appropriate adjustments or more specific parameters.` 
                                 }, {
-                                  body: { userLocation }
+                                  body: { userLocation, timezone }
                                 });
                               }}
                               className="text-xs bg-red-600 text-white px-4 py-2 rounded hover:bg-red-700 transition-colors font-bold shadow-sm"
@@ -292,7 +294,7 @@ export default function Home() {
                                 sendMessage({ 
                                   text: `Analyze and fix the failure for tool "${toolName}" with parameters ${JSON.stringify(toolInvocation.input)}. Error: "${toolInvocation.errorText}". Propose a corrected set of parameters or an alternative approach.` 
                                 }, {
-                                  body: { userLocation }
+                                  body: { userLocation, timezone }
                                 });
                               }}
                               className="text-xs bg-slate-800 text-white px-4 py-2 rounded hover:bg-slate-900 transition-colors font-bold shadow-sm"
@@ -29,6 +29,13 @@ export class MCPClient {
     );
   }
 
+  /**
+   * The URL of the MCP server this client targets.
+   */
+  getServerUrl(): string {
+    return this.serverUrl;
+  }
+
   /**
    * Initialize the connection to the MCP server.
    */
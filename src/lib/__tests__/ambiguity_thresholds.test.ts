@@ -0,0 +1,48 @@
+import { resolveAmbiguity, DEFAULT_AMBIGUITY_THRESHOLDS } from "../ambiguity";
+import type { Intent } from "../schema";
+
+function makeIntent(type: Intent["type"], confidence: number): Intent {
+  return {
+    id: crypto.randomUUID(),
+    type,
+    confidence,
+    parameters: { query: "coffee near me" },
+    rawText: "coffee near me",
+    metadata: {
+      version: "1.0.0",
+      timestamp: new Date().toISOString(),
+      source: "user_input",
+    },
+  };
+}
+
+async function runAmbiguityThresholdsTest() {
+  console.log("--- AMBIGUITY THRESHOLDS TEST ---");
+
+  // Default thresholds: 0.8 is below the 0.85 confidence bar
+  const defaults = resolveAmbiguity([makeIntent("SEARCH", 0.8)]);
+  if (!defaults.isAmbiguous || DEFAULT_AMBIGUITY_THRESHOLDS.confidence !== 0.85) {
+    console.error("FAIL: 0.8 confidence should be ambiguous with default thresholds");
+    process.exit(1);
+  }
+
+  // A lower confidence bar lets the same intent through
+  const relaxed = resolveAmbiguity([makeIntent("SEARCH", 0.8)], { confidence: 0.7 });
+  if (relaxed.isAmbiguous || relaxed.primary.type !== "SEARCH") {
+    console.error("FAIL: 0.8 confidence should proceed with a 0.7 threshold");
+    process.exit(1);
+  }
+
+  // The gap threshold is tuned independently
+  const close = [makeIntent("SEARCH", 0.95), makeIntent("QUERY", 0.88)];
+  const strictGap = resolveAmbiguity(close.map(i => ({ ...i })));
+  const looseGap = resolveAmbiguity(close.map(i => ({ ...i })), { gap: 0.05 });
+  if (!strictGap.isAmbiguous || looseGap.isAmbiguous) {
+    console.error(`FAIL: A 0.07 lead should be ambiguous by default but not with a 0.05 gap (default=${strictGap.isAmbiguous}, loose=${looseGap.isAmbiguous})`);
+    process.exit(1);
+  }
+
+  console.log("PASS: Ambiguity thresholds are configurable per call.");
+}
+
+runAmbiguityThresholdsTest();
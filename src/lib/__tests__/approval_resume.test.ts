@@ -0,0 +1,142 @@
+import { executePlan, resumeExecution, ToolExecutor } from "../engine/orchestrator";
+import { PlanStep } from "../engine/types";
+import { getMemoryClient } from "../engine/memory";
+import { signApprovalToken } from "../auth";
+import { randomUUID } from "crypto";
+import { makePlan, makeStep } from "./plan_fixtures";
+
+// Token consumption keys kept in memory instead of Redis
+function stubMemory() {
+  const locks = new Set<string>();
+  (getMemoryClient() as any).acquireLock = async (key: string) => {
+    if (locks.has(key)) return false;
+    locks.add(key);
+    return true;
+  };
+}
+
+async function testApprovalResume() {
+  console.log("--- TEST: Resume Requires a Plan-Bound Approval Token ---");
+  stubMemory();
+
+  const calls: string[] = [];
+  const executor: ToolExecutor = {
+    execute: async (name) => {
+      calls.push(name);
+      return { success: true, output: { sent: true }, latency_ms: 1 };
+    },
+  };
+
+  const step = makeStep(0, "send_message", {
+    parameters: { to: "Alex", body: "Running late" },
+    description: "Send message",
+    requires_confirmation: true,
+  });
+  const plan = makePlan([step], "Approval resume test plan");
+
+  const paused = await executePlan(plan, executor, { persistState: false });
+  if (paused.state.status !== "AWAITING_CONFIRMATION" || !paused.approval_token || calls.length !== 0) {
+    console.error(`FAIL: Expected a paused execution with an approval token, got ${paused.state.status}`);
+    process.exit(1);
+  }
+  console.log("PASS: Pausing for confirmation issues an approval token.");
+
+  const expectRejected = async (name: string, approvalToken: string | undefined, state = paused.state) => {
+    try {
+      await resumeExecution(state, executor, { persistState: false, approvalToken });
+      console.error(`FAIL: ${name} should have been rejected`);
+      process.exit(1);
+    } catch (error: any) {
+      if (error?.code !== "APPROVAL_TOKEN_INVALID" || calls.length !== 0) {
+        console.error(`FAIL: ${name} rejected with unexpected error ${JSON.stringify(error)}`);
+        process.exit(1);
+      }
+      console.log(`PASS: ${name} rejected (${error.message}).`);
+    }
+  };
+
+  await expectRejected("Missing token", undefined);
+  await expectRejected("Expired token", await signApprovalToken(plan, -10));
+  await expectRejected("Token for another plan", await signApprovalToken(makePlan([step], "Approval resume test plan")));
+  await expectRejected(
+    "Token for another execution",
+    await signApprovalToken(plan, undefined, { execution_id: randomUUID(), step_ids: [step.id] })
+  );
+
+  // The persisted plan was changed after the user approved it
+  const tamperedState = {
+    ...paused.state,
+    plan: { ...plan, steps: [{ ...step, parameters: { to: "Everyone", body: "Running late" } }] },
+  };
+  await expectRejected("Token for a modified plan", paused.approval_token, tamperedState);
+
+  const resumed = await resumeExecution(paused.state, executor, {
+    persistState: false,
+    approvalToken: paused.approval_token,
+  });
+
+  if (resumed.success && resumed.state.status === "COMPLETED" && calls.join(",") === "send_message") {
+    console.log("PASS: Valid token resumes and runs the confirmed step once.");
+  } else {
+    console.error(`FAIL: Expected COMPLETED after approval, got ${resumed.state.status}, calls: ${calls.join(",")}`);
+    process.exit(1);
+  }
+
+  // The same approval cannot be presented twice
+  calls.length = 0;
+  await expectRejected("Replayed token", paused.approval_token);
+
+  // Approving one step does not approve a later step that also needs confirmation
+  const book: PlanStep = { ...step, id: randomUUID(), tool_name: "book_flight", parameters: { flight: "UA 100" } };
+  const pay: PlanStep = {
+    ...step,
+    id: randomUUID(),
+    step_number: 1,
+    tool_name: "charge_card",
+    parameters: { amount: 420 },
+    dependencies: [book.id],
+  };
+  const twoStepPlan = makePlan([book, pay], "Approval resume test plan");
+
+  const firstPause = await executePlan(twoStepPlan, executor, { persistState: false });
+  const secondPause = await resumeExecution(firstPause.state, executor, {
+    persistState: false,
+    approvalToken: firstPause.approval_token,
+  });
+
+  if (secondPause.state.status !== "AWAITING_CONFIRMATION" || calls.join(",") !== "book_flight" || !secondPause.approval_token) {
+    console.error(`FAIL: Expected a second pause before charge_card, got ${secondPause.state.status}, calls: ${calls.join(",")}`);
+    process.exit(1);
+  }
+  console.log("PASS: A later confirmation step pauses again with its own token.");
+
+  try {
+    await resumeExecution(secondPause.state, executor, { persistState: false, approvalToken: firstPause.approval_token });
+    console.error("FAIL: The first step's token should not approve the second step");
+    process.exit(1);
+  } catch (error: any) {
+    if (error?.code !== "APPROVAL_TOKEN_INVALID" || calls.includes("charge_card")) {
+      console.error(`FAIL: Unexpected result reusing the first token ${JSON.stringify(error)}`);
+      process.exit(1);
+    }
+    console.log(`PASS: The first step's token does not approve the second (${error.message}).`);
+  }
+
+  const finished = await resumeExecution(secondPause.state, executor, {
+    persistState: false,
+    approvalToken: secondPause.approval_token,
+  });
+  if (finished.state.status === "COMPLETED" && calls.join(",") === "book_flight,charge_card") {
+    console.log("PASS: The second token completes the plan.");
+  } else {
+    console.error(`FAIL: Expected COMPLETED after the second approval, got ${finished.state.status}, calls: ${calls.join(",")}`);
+    process.exit(1);
+  }
+
+  console.log("--- ALL TESTS PASSED ---");
+}
+
+testApprovalResume().catch(err => {
+  console.error(err);
+  process.exit(1);
+});
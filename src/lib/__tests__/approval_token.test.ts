@@ -0,0 +1,71 @@
+import { signApprovalToken, verifyApprovalToken, signServiceToken, ApprovalBinding } from "../auth";
+import { randomUUID } from "crypto";
+
+async function runApprovalTokenTest() {
+  console.log("--- APPROVAL TOKEN TEST ---");
+
+  const plan = {
+    id: randomUUID(),
+    steps: [
+      { tool_name: "book_restaurant_table", parameters: { restaurant_name: "Nopa", party_size: 2 } },
+    ],
+  };
+
+  let failures = 0;
+  const check = async (name: string, token: string, target: typeof plan, expectValid: boolean, binding?: ApprovalBinding) => {
+    const result = await verifyApprovalToken(token, target, binding);
+    if (result.valid === expectValid) {
+      console.log(`PASS: ${name}${result.reason ? ` (${result.reason})` : ""}`);
+    } else {
+      console.error(`FAIL: ${name} expected valid=${expectValid}, got ${JSON.stringify(result)}`);
+      failures++;
+    }
+  };
+
+  const token = await signApprovalToken(plan);
+
+  // Happy path
+  await check("Token accepted for the approved plan", token, plan, true);
+
+  // Expired token
+  await check("Expired token rejected", await signApprovalToken(plan, -10), plan, false);
+
+  // Token presented for a different plan
+  await check("Token rejected for another plan", token, { ...plan, id: randomUUID() }, false);
+
+  // Plan modified after approval
+  const modified = { ...plan, steps: [{ tool_name: "book_restaurant_table", parameters: { restaurant_name: "Nopa", party_size: 12 } }] };
+  await check("Token rejected for a modified plan", token, modified, false);
+
+  // Tampered signature
+  const tampered = token.slice(0, -2) + (token.endsWith("AA") ? "BB" : "AA");
+  await check("Tampered token rejected", tampered, plan, false);
+
+  // Service tokens are not approvals
+  await check("Service token rejected", await signServiceToken({ service: "intention-engine" }), plan, false);
+
+  // Tokens bound to a paused execution only approve those steps of that execution
+  const binding = { execution_id: randomUUID(), step_ids: [randomUUID(), randomUUID()] };
+  const bound = await signApprovalToken(plan, 900, binding);
+  await check("Bound token accepted for its steps in any order", bound, plan, true, { ...binding, step_ids: [...binding.step_ids].reverse() });
+  await check("Bound token rejected for another execution", bound, plan, false, { ...binding, execution_id: randomUUID() });
+  await check("Bound token rejected for other steps", bound, plan, false, { ...binding, step_ids: [binding.step_ids[0]] });
+  await check("Unbound token rejected where a binding is required", token, plan, false, binding);
+
+  const first = await verifyApprovalToken(token, plan);
+  const second = await verifyApprovalToken(await signApprovalToken(plan), plan);
+  if (first.jti && second.jti && first.jti !== second.jti) {
+    console.log("PASS: Each token carries its own jti.");
+  } else {
+    console.error(`FAIL: Expected distinct jtis, got ${first.jti} and ${second.jti}`);
+    failures++;
+  }
+
+  if (failures > 0) {
+    process.exit(1);
+  }
+
+  console.log("PASS: Approval tokens are bound to plan and expiry.");
+}
+
+runApprovalTokenTest();
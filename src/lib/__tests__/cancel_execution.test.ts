@@ -0,0 +1,85 @@
+import { executePlan, cancelExecution, ToolExecutor } from "../engine/orchestrator";
+import { getStepState } from "../engine/state-machine";
+import { randomUUID } from "crypto";
+import { makePlan, makeStep } from "./plan_fixtures";
+
+async function testCancelExecution() {
+  console.log("--- TEST: Cancel Paused Execution ---");
+
+  const calls: string[] = [];
+  const mockToolExecutor: ToolExecutor = {
+    execute: async (name) => {
+      calls.push(name);
+      return { success: true, output: { ok: true }, latency_ms: 1 };
+    },
+  };
+
+  const search = makeStep(0, "search_restaurant");
+  const book = makeStep(1, "book_restaurant_table", { dependencies: [search.id], requires_confirmation: true });
+
+  const plan = makePlan([search, book], "Cancel test plan", 10000);
+
+  // Test 1: A plan paused for confirmation can be abandoned
+  console.log("Test 1: Cancel from AWAITING_CONFIRMATION...");
+  const paused = await executePlan(plan, mockToolExecutor, { persistState: false });
+  if (paused.state.status !== "AWAITING_CONFIRMATION") {
+    console.error(`FAIL: Expected plan to pause for confirmation, got ${paused.state.status}`);
+    process.exit(1);
+  }
+
+  const cancelled = await cancelExecution(paused.state, mockToolExecutor, { persistState: false });
+  if (cancelled.state.status === "CANCELLED" &&
+      cancelled.state.completed_at &&
+      getStepState(cancelled.state, book.id)?.status === "skipped" &&
+      getStepState(cancelled.state, search.id)?.status === "completed" &&
+      !calls.includes("book_restaurant_table")) {
+    console.log("PASS: Paused execution cancelled and unconfirmed step skipped.");
+  } else {
+    console.error(`FAIL: Unexpected cancelled state ${JSON.stringify(cancelled.state.step_states)}`);
+    process.exit(1);
+  }
+
+  // Test 2: Running and terminal executions cannot be cancelled
+  console.log("Test 2: Reject cancel from EXECUTING and terminal states...");
+  for (const status of ["EXECUTING", "CANCELLED"] as const) {
+    try {
+      await cancelExecution({ ...paused.state, status }, mockToolExecutor, { persistState: false });
+      console.error(`FAIL: Cancel from ${status} should be rejected`);
+      process.exit(1);
+    } catch (error: any) {
+      if (error.code !== "STATE_TRANSITION_INVALID") {
+        console.error(`FAIL: Unexpected error for ${status}: ${error.message}`);
+        process.exit(1);
+      }
+    }
+  }
+  console.log("PASS: Cancel rejected outside paused states.");
+
+  // Test 3: Cancelling compensates steps that already completed
+  console.log("Test 3: Cancel compensates completed bookings...");
+  const hotel = makeStep(0, "book_hotel");
+  hotel.compensation = { tool_name: "cancel_hotel", parameters: { booking_id: `$${hotel.id}.ok` } };
+  const charge = makeStep(1, "charge_card", { dependencies: [hotel.id], requires_confirmation: true });
+
+  const bookingPaused = await executePlan({ ...plan, id: randomUUID(), steps: [hotel, charge] }, mockToolExecutor, { persistState: false });
+  const bookingCancelled = await cancelExecution(bookingPaused.state, mockToolExecutor, { persistState: false });
+
+  if (
+    bookingCancelled.state.status === "CANCELLED" &&
+    calls.includes("cancel_hotel") && !calls.includes("charge_card") &&
+    getStepState(bookingCancelled.state, hotel.id)?.status === "compensated" &&
+    getStepState(bookingCancelled.state, charge.id)?.status === "skipped"
+  ) {
+    console.log("PASS: Completed booking compensated on cancel.");
+  } else {
+    console.error(`FAIL: Expected the hotel booking compensated, calls: ${calls.join(",")}, steps: ${JSON.stringify(bookingCancelled.state.step_states)}`);
+    process.exit(1);
+  }
+
+  console.log("--- ALL TESTS PASSED ---");
+}
+
+testCancelExecution().catch(err => {
+  console.error(err);
+  process.exit(1);
+});
@@ -0,0 +1,52 @@
+import { normalizeIntent } from "../normalization";
+import { resolveAmbiguity } from "../ambiguity";
+
+async function runClarificationTest() {
+  console.log("--- CLARIFICATION QUESTION TEST ---");
+
+  const modelId = "sim-v1";
+
+  // Case 1: Transport intent missing a destination
+  const raw1 = "Get me an Uber";
+  const cand1 = { type: "ACTION", confidence: 0.6, parameters: { capability: "mobility_request", arguments: { service: "uber", pickup_location: "home" } } };
+  const result1 = resolveAmbiguity([normalizeIntent(cand1, raw1, modelId)]);
+
+  console.log(`Input: "${raw1}"`);
+  console.log(`Question: ${result1.clarificationQuestion}`);
+
+  if (result1.clarificationQuestion !== "Where should the ride take you?") {
+    console.error("FAIL: Transport intent missing destination should ask where the ride goes");
+    process.exit(1);
+  }
+
+  // Case 2: Reservation intent missing party size (and date/time) asks for party size first
+  const raw2 = "Book a table at Nopa";
+  const cand2 = { type: "ACTION", confidence: 0.6, parameters: { capability: "book_restaurant_table", arguments: { restaurant_name: "Nopa" } } };
+  const result2 = resolveAmbiguity([normalizeIntent(cand2, raw2, modelId)]);
+
+  console.log(`Input: "${raw2}"`);
+  console.log(`Question: ${result2.clarificationQuestion}`);
+
+  if (result2.clarificationQuestion !== "How many people?") {
+    console.error("FAIL: Reservation missing party size should ask how many people");
+    process.exit(1);
+  }
+
+  // Case 3: Multiple missing required fields asks the most important first
+  const raw3 = "Put something on my calendar";
+  const cand3 = { type: "SCHEDULE", confidence: 0.9, parameters: {} };
+  const intent3 = normalizeIntent(cand3, raw3, modelId);
+  const result3 = resolveAmbiguity([intent3]);
+
+  console.log(`Input: "${raw3}"`);
+  console.log(`Question: ${result3.clarificationQuestion}`);
+
+  if (intent3.type !== "CLARIFICATION_REQUIRED" || result3.clarificationQuestion !== "What would you like to schedule?") {
+    console.error("FAIL: Schedule intent missing action and time should ask what to schedule first");
+    process.exit(1);
+  }
+
+  console.log("PASS: Clarification questions are intent-specific.");
+}
+
+runClarificationTest();
@@ -0,0 +1,95 @@
+import { resolveRelativeTime, parseClockTime } from "../date-utils";
+
+async function runDateResolutionTest() {
+  console.log("--- RELATIVE DATE RESOLUTION TEST ---");
+
+  // Tuesday, 10 Feb 2026 at noon UTC
+  const now = new Date("2026-02-10T12:00:00Z");
+
+  const cases: [string, string | undefined][] = [
+    ["tomorrow at 3pm", "2026-02-11T15:00:00Z"],
+    ["tomorrow morning", "2026-02-11T09:00:00Z"],
+    ["tonight", "2026-02-10T20:00:00Z"],
+    ["today at 5:30 pm", "2026-02-10T17:30:00Z"],
+    ["next Friday evening", "2026-02-13T19:00:00Z"],
+    ["next Tuesday", "2026-02-17T12:00:00Z"],
+    ["in 2 hours", "2026-02-10T14:00:00Z"],
+    ["in 3 days", "2026-02-13T12:00:00Z"],
+    ["2026-03-01T10:00:00Z", "2026-03-01T10:00:00Z"],
+    ["sometime soon", undefined],
+    ["tomorrow at 1400 hours", "2026-02-11T14:00:00Z"],
+    ["tomorrow at seven o'clock in the evening", "2026-02-11T19:00:00Z"],
+    // Midnight starting tomorrow, less a quarter hour, is still today
+    ["tomorrow at quarter to 12 am", "2026-02-10T23:45:00Z"],
+    // Only partly relative: left for the LLM parser instead of resolving to the wrong day
+    ["March 3rd at 7pm", undefined],
+    ["the second Friday of March", undefined],
+  ];
+
+  let failures = 0;
+  for (const [input, expected] of cases) {
+    const actual = resolveRelativeTime(input, now, "UTC");
+    if (actual === expected) {
+      console.log(`PASS: "${input}" -> ${actual}`);
+    } else {
+      console.error(`FAIL: "${input}" expected ${expected}, got ${actual}`);
+      failures++;
+    }
+  }
+
+  // Days and times are read on the user's wall clock
+  const zoneCases: [string, Date, string, string][] = [
+    ["tomorrow at 3pm", now, "America/New_York", "2026-02-11T20:00:00Z"],
+    // 10pm on the 10th in New York is already the 11th in UTC
+    ["tomorrow at 9am", new Date("2026-02-11T03:00:00Z"), "America/New_York", "2026-02-11T14:00:00Z"],
+    // Clocks spring forward overnight
+    ["tomorrow at 3pm", new Date("2026-03-07T20:00:00Z"), "America/New_York", "2026-03-08T19:00:00Z"],
+    ["in 2 hours", now, "Asia/Tokyo", "2026-02-10T14:00:00Z"],
+  ];
+
+  for (const [input, zoneNow, timeZone, expected] of zoneCases) {
+    const actual = resolveRelativeTime(input, zoneNow, timeZone);
+    if (actual === expected) {
+      console.log(`PASS: "${input}" in ${timeZone} -> ${actual}`);
+    } else {
+      console.error(`FAIL: "${input}" in ${timeZone} expected ${expected}, got ${actual}`);
+      failures++;
+    }
+  }
+
+  // Clock formats normalize to 24-hour time; hours without a meridiem are left as stated
+  const clockCases: [string, string | undefined][] = [
+    ["1400 hours", "14:00"],
+    ["seven o'clock", "07:00"],
+    ["half past six", "06:30"],
+    ["half past six pm", "18:30"],
+    ["quarter to seven in the evening", "18:45"],
+    ["quarter past nine am", "09:15"],
+    ["quarter to twelve pm", "11:45"],
+    ["quarter to 12 am", "23:45"],
+    ["quarter to one am", "00:45"],
+    ["quarter to one", "12:45"],
+    ["seven pm", "19:00"],
+    ["7:30 pm", "19:30"],
+    ["dinner at 8", undefined],
+  ];
+
+  for (const [input, expected] of clockCases) {
+    const time = parseClockTime(input);
+    const actual = time && `${String(time.hours).padStart(2, "0")}:${String(time.minutes).padStart(2, "0")}`;
+    if (actual === expected) {
+      console.log(`PASS: "${input}" -> ${actual}`);
+    } else {
+      console.error(`FAIL: "${input}" expected ${expected}, got ${actual}`);
+      failures++;
+    }
+  }
+
+  if (failures > 0) {
+    process.exit(1);
+  }
+
+  console.log("PASS: Relative dates resolved deterministically.");
+}
+
+runDateResolutionTest();
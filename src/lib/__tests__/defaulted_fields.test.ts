@@ -0,0 +1,49 @@
+import { normalizeIntent } from "../normalization";
+
+async function runDefaultedFieldsTest() {
+  console.log("--- DEFAULTED FIELD CONFIDENCE TEST ---");
+
+  const modelId = "sim-v1";
+
+  // Fully specified ride keeps the parser's confidence
+  const specified = normalizeIntent(
+    { type: "ACTION", confidence: 1.0, parameters: { capability: "mobility_request", arguments: { service: "uber", pickup_location: "home", destination_location: "SFO" } } },
+    "get me an uber from home to SFO",
+    modelId
+  );
+
+  // Bare request where the parser filled in placeholder endpoints
+  const bare = normalizeIntent(
+    { type: "ACTION", confidence: 1.0, parameters: { capability: "mobility_request", arguments: { service: "uber", pickup_location: "Current location", destination_location: "Unknown destination" } } },
+    "get me an uber",
+    modelId
+  );
+
+  // Same request with the endpoints omitted entirely
+  const omitted = normalizeIntent(
+    { type: "ACTION", confidence: 1.0, parameters: { capability: "mobility_request", arguments: { service: "uber" } } },
+    "get me an uber",
+    modelId
+  );
+
+  console.log(`Specified: ${specified.confidence}, placeholders: ${bare.confidence}, omitted: ${omitted.confidence}`);
+
+  if (specified.confidence !== 1.0) {
+    console.error("FAIL: Fully specified ride should not be penalized");
+    process.exit(1);
+  }
+
+  if (bare.confidence >= 0.6 || omitted.confidence >= 0.6) {
+    console.error("FAIL: Ride with defaulted endpoints should score materially lower");
+    process.exit(1);
+  }
+
+  if (!bare.explanation?.includes("destination_location")) {
+    console.error(`FAIL: Explanation should name the defaulted fields, got ${bare.explanation}`);
+    process.exit(1);
+  }
+
+  console.log("PASS: Defaulted ride endpoints reduce confidence.");
+}
+
+runDefaultedFieldsTest();
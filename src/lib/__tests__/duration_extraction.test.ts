@@ -0,0 +1,63 @@
+import { extractDuration } from "../date-utils";
+import { normalizeIntent } from "../normalization";
+import { createExecutionPlan } from "../execution_plan";
+
+async function runDurationExtractionTest() {
+  console.log("--- DURATION EXTRACTION TEST ---");
+
+  const cases: [string, string | undefined][] = [
+    ["block off time for 2 hours", "PT2H"],
+    ["a 30 minute meeting", "PT30M"],
+    ["a 2-hour workshop", "PT2H"],
+    ["1 hour 30 minutes with the team", "PT1H30M"],
+    ["1 hour and 30 minutes", "PT1H30M"],
+    ["half an hour", "PT30M"],
+    ["90 minutes", "PT1H30M"],
+    ["I'm busy all afternoon", "PT4H"],
+    ["remind me in 2 hours", undefined],
+    ["dinner tomorrow at 7pm", undefined],
+    ["remind me 30 minutes before the flight", undefined],
+    ["call her 2 hours after lunch", undefined],
+    ["running 15 minutes late", undefined],
+    ["a 1 hour 30 minutes session, 10 minutes early", "PT1H30M"],
+    ["0.995 hours", "PT1H"],
+    ["tomorrow at 1400 hours", undefined],
+    ["a 2 hour meeting at 0930 hrs", "PT2H"],
+  ];
+
+  let failures = 0;
+  for (const [input, expected] of cases) {
+    const actual = extractDuration(input);
+    if (actual === expected) {
+      console.log(`PASS: "${input}" -> ${actual}`);
+    } else {
+      console.error(`FAIL: "${input}" expected ${expected}, got ${actual}`);
+      failures++;
+    }
+  }
+
+  // Duration flows through normalization into the calendar step's end time
+  const raw = "Schedule a 90 minute review";
+  const intent = normalizeIntent(
+    { type: "SCHEDULE", confidence: 0.95, parameters: { action: "create", temporal_expression: "2026-03-01T10:00:00Z", title: "Review" } },
+    raw,
+    "sim-v1"
+  );
+  const plan = createExecutionPlan(intent);
+  const event = (plan.steps[0].parameters as any).events[0];
+
+  if (intent.parameters.duration === "PT1H30M" && event.end_time === "2026-03-01T11:30:00.000Z") {
+    console.log(`PASS: Calendar event ends at ${event.end_time}`);
+  } else {
+    console.error(`FAIL: Expected duration PT1H30M and end 11:30, got ${intent.parameters.duration} / ${event.end_time}`);
+    failures++;
+  }
+
+  if (failures > 0) {
+    process.exit(1);
+  }
+
+  console.log("PASS: Durations extracted as ISO 8601.");
+}
+
+runDurationExtractionTest();
@@ -0,0 +1,90 @@
+import { executePlan, ToolExecutor } from "../engine/orchestrator";
+import { Plan, TraceEntry } from "../engine/types";
+import { randomUUID } from "crypto";
+
+async function testExecutionEvents() {
+  console.log("--- TEST: Execution Lifecycle Events ---");
+
+  const mockToolExecutor: ToolExecutor = {
+    execute: async () => ({ success: true, output: { result: "ok" }, latency_ms: 1 }),
+  };
+
+  const firstId = randomUUID();
+  const secondId = randomUUID();
+
+  const plan: Plan = {
+    id: randomUUID(),
+    intent_id: randomUUID(),
+    steps: [
+      {
+        id: firstId,
+        step_number: 0,
+        tool_name: "tool1",
+        parameters: {},
+        dependencies: [],
+        description: "First",
+        requires_confirmation: false,
+        timeout_ms: 5000
+      },
+      {
+        id: secondId,
+        step_number: 1,
+        tool_name: "tool2",
+        parameters: {},
+        dependencies: [firstId],
+        description: "Second (depends on first)",
+        requires_confirmation: false,
+        timeout_ms: 5000
+      }
+    ],
+    constraints: {
+      max_steps: 10,
+      max_total_tokens: 1000,
+      max_execution_time_ms: 10000
+    },
+    metadata: {
+      version: "1.0.0",
+      created_at: new Date().toISOString(),
+      planning_model_id: "test",
+      estimated_total_tokens: 0,
+      estimated_latency_ms: 0
+    },
+    summary: "Lifecycle events test plan"
+  };
+
+  const events: TraceEntry[] = [];
+  await executePlan(plan, mockToolExecutor, {
+    persistState: false,
+    traceCallback: (entry) => events.push(entry),
+  });
+
+  const describe = (e: TraceEntry) =>
+    e.event === "state_changed"
+      ? `state_changed:${(e.output as { to: string }).to}`
+      : `${e.event}:${e.step_id === firstId ? "first" : "second"}`;
+
+  const actual = events.map(describe);
+  const expected = [
+    "state_changed:PLANNED",
+    "state_changed:EXECUTING",
+    "step_started:first",
+    "step_completed:first",
+    "step_started:second",
+    "step_completed:second",
+    "state_changed:COMPLETED",
+  ];
+
+  console.log(`Events: ${actual.join(", ")}`);
+
+  if (JSON.stringify(actual) === JSON.stringify(expected)) {
+    console.log("PASS: Lifecycle events emitted in order.");
+  } else {
+    console.error(`FAIL: Expected ${expected.join(", ")}`);
+    process.exit(1);
+  }
+}
+
+testExecutionEvents().catch(err => {
+  console.error(err);
+  process.exit(1);
+});
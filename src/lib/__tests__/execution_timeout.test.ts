@@ -0,0 +1,132 @@
+import { executePlan, resumeExecution, ToolExecutor } from "../engine/orchestrator";
+import { getStepState } from "../engine/state-machine";
+import { makePlan, makeStep } from "./plan_fixtures";
+
+async function testExecutionTimeout() {
+  console.log("--- TEST: Execution Time Budget ---");
+
+  // Test 1: A booking still in flight at the deadline is aborted and the budget holds
+  console.log("Test 1: In-flight step aborted at the deadline...");
+  const calls: string[] = [];
+  let hotelAborted = false;
+  const slowExecutor: ToolExecutor = {
+    execute: async (name, _params, _timeoutMs, signal) => {
+      calls.push(name);
+      if (name === "book_hotel") {
+        await new Promise<void>((resolve, reject) => {
+          const timer = setTimeout(resolve, 400);
+          signal?.addEventListener("abort", () => {
+            hotelAborted = true;
+            clearTimeout(timer);
+            reject(new Error("aborted"));
+          });
+        });
+      }
+      return { success: true, output: { booking_id: name }, latency_ms: 1 };
+    },
+  };
+
+  const table = makeStep(0, "book_table");
+  table.compensation = { tool_name: "cancel_table", parameters: { booking_id: `$${table.id}.booking_id` } };
+  const hotel = makeStep(1, "book_hotel", { dependencies: [table.id] });
+  hotel.compensation = { tool_name: "cancel_hotel", parameters: { booking_id: `$${hotel.id}.booking_id` } };
+  const notify = makeStep(2, "send_confirmation", { dependencies: [hotel.id] });
+
+  const started = Date.now();
+  const result = await executePlan(makePlan([table, hotel, notify], "Timeout test plan", 150), slowExecutor, { persistState: false });
+  const elapsed = Date.now() - started;
+  console.log(`Status: ${result.state.status} after ${elapsed}ms, error: ${JSON.stringify(result.error)}, calls: ${calls.join(", ")}`);
+
+  if (result.success || result.state.status !== "TIMEOUT" || result.error?.code !== "EXECUTION_TIMEOUT") {
+    console.error("FAIL: Expected the execution to end in TIMEOUT with EXECUTION_TIMEOUT");
+    process.exit(1);
+  }
+
+  if (elapsed >= 300 || !hotelAborted) {
+    console.error(`FAIL: Expected the hotel call aborted near the 150ms budget, took ${elapsed}ms (aborted=${hotelAborted})`);
+    process.exit(1);
+  }
+
+  if (calls.includes("send_confirmation")) {
+    console.error("FAIL: No step should start after the time budget ran out");
+    process.exit(1);
+  }
+
+  if (
+    !calls.includes("cancel_table") || calls.includes("cancel_hotel") ||
+    getStepState(result.state, table.id)?.status !== "compensated" ||
+    getStepState(result.state, hotel.id)?.error?.code !== "STEP_TIMEOUT"
+  ) {
+    console.error("FAIL: The on-time booking should be compensated and the aborted one marked STEP_TIMEOUT");
+    process.exit(1);
+  }
+  console.log(`PASS: Execution timed out within budget (${elapsed}ms), aborted the hotel call and compensated the table.`);
+
+  // Test 1b: A tool that ignores the abort and succeeds late is compensated once it lands
+  console.log("Test 1b: Late completion compensated...");
+  const stubbornCalls: string[] = [];
+  const stubbornExecutor: ToolExecutor = {
+    execute: async (name) => {
+      stubbornCalls.push(name);
+      if (name === "book_hotel") {
+        await new Promise((resolve) => setTimeout(resolve, 250));
+      }
+      return { success: true, output: { booking_id: name }, latency_ms: 1 };
+    },
+  };
+
+  const lateHotel = makeStep(0, "book_hotel");
+  lateHotel.compensation = { tool_name: "cancel_hotel", parameters: { booking_id: `$${lateHotel.id}.booking_id` } };
+
+  const lateStarted = Date.now();
+  const lateResult = await executePlan(makePlan([lateHotel], "Late completion test plan", 100), stubbornExecutor, { persistState: false });
+  const lateElapsed = Date.now() - lateStarted;
+
+  if (lateResult.state.status !== "TIMEOUT" || lateElapsed >= 200 || stubbornCalls.includes("cancel_hotel")) {
+    console.error(`FAIL: Expected TIMEOUT before the hotel call returned, got ${lateResult.state.status} after ${lateElapsed}ms`);
+    process.exit(1);
+  }
+
+  await new Promise((resolve) => setTimeout(resolve, 300));
+  if (!stubbornCalls.includes("cancel_hotel")) {
+    console.error(`FAIL: The late hotel booking was never compensated, calls: ${stubbornCalls.join(", ")}`);
+    process.exit(1);
+  }
+  console.log(`PASS: Returned after ${lateElapsed}ms and compensated the late booking when it landed.`);
+
+  // Test 2: A timed-out execution is terminal and cannot be resumed
+  console.log("Test 2: Timed-out execution is not resumed...");
+  const callsBeforeResume = calls.length;
+  const resumed = await resumeExecution(result.state, slowExecutor, { persistState: false });
+
+  if (resumed.success || resumed.state.status !== "TIMEOUT" || calls.length !== callsBeforeResume) {
+    console.error(`FAIL: Resume re-executed a timed-out execution (status ${resumed.state.status})`);
+    process.exit(1);
+  }
+  console.log("PASS: Resume leaves the timed-out execution as is.");
+
+  // Test 3: Retry backoff does not overrun the time budget
+  console.log("Test 3: Backoff bounded by the time budget...");
+  const failingExecutor: ToolExecutor = {
+    execute: async () => ({ success: false, error: "network timeout", latency_ms: 1 }),
+  };
+  const flaky = makeStep(0, "flaky_search", { retry_policy: { max_attempts: 3, backoff_ms: 2000 } });
+
+  const backoffStarted = Date.now();
+  const backoffResult = await executePlan(makePlan([flaky], "Backoff timeout test plan", 200), failingExecutor, { persistState: false });
+  const backoffElapsed = Date.now() - backoffStarted;
+
+  if (backoffResult.state.status !== "TIMEOUT" || backoffElapsed >= 400) {
+    console.error(`FAIL: Expected TIMEOUT within the budget, got ${backoffResult.state.status} after ${backoffElapsed}ms`);
+    process.exit(1);
+  }
+  console.log(`PASS: Backoff cut short at the deadline (${backoffElapsed}ms).`);
+
+  console.log("--- ALL TESTS PASSED ---");
+  process.exit(0);
+}
+
+testExecutionTimeout().catch(err => {
+  console.error(err);
+  process.exit(1);
+});
@@ -0,0 +1,133 @@
+import { normalizeIntent } from "../normalization";
+import type { IntentType } from "../schema";
+
+/**
+ * Regression corpus for intent normalization.
+ * Each case pairs a raw utterance with a recorded LLM candidate and the expected
+ * normalized result. Extend this table when changing normalization rules.
+ */
+interface CorpusCase {
+  input: string;
+  candidate: any;
+  expectedType: IntentType;
+  expectedFields: Record<string, unknown>;
+}
+
+const CORPUS: CorpusCase[] = [
+  {
+    input: "schedule a meeting tomorrow at 3pm",
+    candidate: { type: "SCHEDULE", confidence: 0.95, parameters: { action: "create", temporal_expression: "tomorrow at 3pm" } },
+    expectedType: "SCHEDULE",
+    expectedFields: { action: "CREATE", temporal_expression: "tomorrow at 3pm" },
+  },
+  {
+    input: "book dinner with Sarah on Friday",
+    candidate: { type: "schedule", confidence: 0.9, parameters: { action: "book", temporal_expression: "Friday 19:00" } },
+    expectedType: "SCHEDULE",
+    expectedFields: { action: "SCHEDULE" },
+  },
+  {
+    input: "set up a call next week",
+    candidate: { type: "SCHEDULE", confidence: "0.88", parameters: { action: "set up", temporal_expression: "next week" } },
+    expectedType: "SCHEDULE",
+    expectedFields: { action: "SCHEDULE", temporal_expression: "next week" },
+  },
+  {
+    input: "find italian restaurants near me",
+    candidate: { type: "SEARCH", confidence: 0.93, parameters: { query: "italian restaurants", scope: "LOCAL" } },
+    expectedType: "SEARCH",
+    expectedFields: { query: "italian restaurants", scope: "LOCAL" },
+  },
+  {
+    input: "look up flights",
+    candidate: { type: "SEARCH", confidence: 0.8, parameters: { query: "flights" } },
+    expectedType: "CLARIFICATION_REQUIRED",
+    expectedFields: { query: "flights", missingFields: ["scope"] },
+  },
+  {
+    input: "get me an uber to the airport",
+    candidate: { type: "ACTION", confidence: 0.94, parameters: { capability: "mobility_request", arguments: { service: "uber", pickup_location: "home", destination_location: "SFO" } } },
+    expectedType: "ACTION",
+    expectedFields: { capability: "mobility_request" },
+  },
+  {
+    input: "send an email to the team",
+    candidate: { type: "action", confidence: 0.9, parameters: { capability: "send_email" } },
+    expectedType: "CLARIFICATION_REQUIRED",
+    expectedFields: { missingFields: ["arguments"] },
+  },
+  {
+    input: "what's the weather in Tokyo",
+    candidate: { type: "QUERY", confidence: 0.97, parameters: { target_object: "weather", location: "Tokyo" } },
+    expectedType: "QUERY",
+    expectedFields: { target_object: "weather", location: "Tokyo" },
+  },
+  {
+    input: "plan my weekend trip to Napa",
+    candidate: { type: "PLANNING", confidence: 0.9, parameters: { goal: "weekend trip", destination: "Napa" } },
+    expectedType: "PLANNING",
+    expectedFields: { destination: "Napa" },
+  },
+  {
+    input: "analyze my spending this month",
+    candidate: { type: "ANALYSIS", confidence: 0.91, parameters: { context: "spending", period: "month" } },
+    expectedType: "ANALYSIS",
+    expectedFields: { context: "spending" },
+  },
+  {
+    input: "ghghghgh",
+    candidate: { type: "UNKNOWN", confidence: 0.1, parameters: {} },
+    expectedType: "UNKNOWN",
+    expectedFields: {},
+  },
+  {
+    input: "hack into my neighbour's wifi",
+    candidate: { type: "REFUSED", confidence: 0.99, parameters: {}, explanation: "Request is harmful." },
+    expectedType: "REFUSED",
+    expectedFields: {},
+  },
+  {
+    input: "do the thing",
+    candidate: { type: "TELEPORT", confidence: 2, parameters: "none" },
+    expectedType: "CLARIFICATION_REQUIRED",
+    expectedFields: {},
+  },
+];
+
+function runCorpus(): string[] {
+  const mismatches: string[] = [];
+
+  for (const testCase of CORPUS) {
+    const intent = normalizeIntent(testCase.candidate, testCase.input, "corpus-v1");
+
+    if (intent.type !== testCase.expectedType) {
+      mismatches.push(`"${testCase.input}": expected type ${testCase.expectedType}, got ${intent.type}`);
+    }
+
+    for (const [field, expected] of Object.entries(testCase.expectedFields)) {
+      const actual = intent.parameters[field];
+      if (JSON.stringify(actual) !== JSON.stringify(expected)) {
+        mismatches.push(`"${testCase.input}": expected ${field}=${JSON.stringify(expected)}, got ${JSON.stringify(actual)}`);
+      }
+    }
+  }
+
+  return mismatches;
+}
+
+async function main() {
+  console.log("--- NORMALIZATION CORPUS TEST ---");
+  console.log(`Cases: ${CORPUS.length}`);
+
+  const mismatches = runCorpus();
+
+  if (mismatches.length > 0) {
+    console.error(`FAIL: ${mismatches.length} mismatch(es):`);
+    mismatches.forEach(m => console.error(`  - ${m}`));
+    process.exit(1);
+  }
+
+  console.log("PASS: All corpus cases normalized as expected.");
+}
+
+main();
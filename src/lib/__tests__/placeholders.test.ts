@@ -0,0 +1,53 @@
+import { resolvePlaceholders } from "../placeholders";
+
+async function runPlaceholderTest() {
+  console.log("--- STEP PLACEHOLDER RESOLUTION TEST ---");
+
+  // Outputs recorded for previously executed steps, keyed by step index
+  const stepOutputs = {
+    0: { lat: 37.7749, lon: -122.4194 },
+    1: [{ name: "Nopa", address: "560 Divisadero St" }, { name: "Zuni Cafe" }],
+  };
+
+  const { parameters, unresolved } = resolvePlaceholders(
+    {
+      lat: "{{step_0.result.lat}}",
+      lon: "{{ step_0.result.lon }}",
+      events: [{
+        restaurant_name: "{{step_1.result[0].name}}",
+        description: "Dinner at {{step_1.result[0].name}}, {{step_1.result[0].address}}",
+      }],
+      party_size: 2,
+    },
+    stepOutputs
+  );
+
+  console.log(`Resolved: ${JSON.stringify(parameters)}`);
+
+  if (parameters.lat !== 37.7749 || parameters.lon !== -122.4194) {
+    console.error("FAIL: Whole-value placeholders should resolve to the raw referenced value");
+    process.exit(1);
+  }
+
+  if (parameters.events[0].restaurant_name !== "Nopa" ||
+      parameters.events[0].description !== "Dinner at Nopa, 560 Divisadero St") {
+    console.error("FAIL: Nested and embedded placeholders should be substituted");
+    process.exit(1);
+  }
+
+  if (parameters.party_size !== 2 || unresolved.length !== 0) {
+    console.error("FAIL: Non-placeholder values should pass through unchanged");
+    process.exit(1);
+  }
+
+  // References to steps that have not produced output are reported, not silently sent
+  const missing = resolvePlaceholders({ restaurant_name: "{{step_3.result[0].name}}" }, stepOutputs);
+  if (missing.unresolved.length !== 1 || missing.parameters.restaurant_name !== "{{step_3.result[0].name}}") {
+    console.error("FAIL: Unresolvable placeholders should be reported");
+    process.exit(1);
+  }
+
+  console.log("PASS: Step placeholders resolved from prior outputs.");
+}
+
+runPlaceholderTest();
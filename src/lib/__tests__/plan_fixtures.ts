@@ -0,0 +1,43 @@
+import { Plan, PlanStep } from "../engine/types";
+import { randomUUID } from "crypto";
+
+/**
+ * Builds a plan step with fresh id and test defaults; overrides win.
+ */
+export function makeStep(stepNumber: number, toolName: string, overrides: Partial<PlanStep> = {}): PlanStep {
+  return {
+    id: randomUUID(),
+    step_number: stepNumber,
+    tool_name: toolName,
+    parameters: {},
+    dependencies: [],
+    description: toolName,
+    requires_confirmation: false,
+    timeout_ms: 5000,
+    ...overrides,
+  };
+}
+
+/**
+ * Wraps steps in a plan with generous constraints and placeholder metadata.
+ */
+export function makePlan(steps: PlanStep[], summary: string, maxExecutionTimeMs = 60000): Plan {
+  return {
+    id: randomUUID(),
+    intent_id: randomUUID(),
+    steps,
+    constraints: {
+      max_steps: 10,
+      max_total_tokens: 1000,
+      max_execution_time_ms: maxExecutionTimeMs,
+    },
+    metadata: {
+      version: "1.0.0",
+      created_at: new Date().toISOString(),
+      planning_model_id: "test",
+      estimated_total_tokens: 0,
+      estimated_latency_ms: 0,
+    },
+    summary,
+  };
+}
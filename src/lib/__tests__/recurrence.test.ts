@@ -0,0 +1,71 @@
+import { extractRecurrence } from "../date-utils";
+import { normalizeIntent } from "../normalization";
+import { createExecutionPlan } from "../execution_plan";
+
+async function runRecurrenceTest() {
+  console.log("--- RECURRENCE EXTRACTION TEST ---");
+
+  const cases: [string, string | undefined][] = [
+    ["every Monday at 10am", "FREQ=WEEKLY;BYDAY=MO"],
+    ["standup every weekday at 9am", "FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR"],
+    ["gym every Monday and Thursday", "FREQ=WEEKLY;BYDAY=MO,TH"],
+    ["water the plants every day", "FREQ=DAILY"],
+    ["weekly sync with design", "FREQ=WEEKLY"],
+    ["monthly budget review", "FREQ=MONTHLY"],
+    ["dinner on Monday at 7pm", undefined],
+    ["yoga each Tuesday", "FREQ=WEEKLY;BYDAY=TU"],
+  ];
+
+  let failures = 0;
+  for (const [input, expected] of cases) {
+    const actual = extractRecurrence(input);
+    if (actual === expected) {
+      console.log(`PASS: "${input}" -> ${actual}`);
+    } else {
+      console.error(`FAIL: "${input}" expected ${expected}, got ${actual}`);
+      failures++;
+    }
+  }
+
+  // Recurrence flows through normalization into the calendar step
+  const raw = "Schedule a 1:1 every Monday at 10am";
+  const intent = normalizeIntent(
+    { type: "SCHEDULE", confidence: 0.95, parameters: { action: "create", temporal_expression: "every Monday at 10am", title: "1:1" } },
+    raw,
+    "sim-v1",
+    "UTC"
+  );
+  const plan = createExecutionPlan(intent);
+  const event = (plan.steps[0].parameters as any).events[0];
+  const start = new Date(intent.parameters.start_time);
+
+  if (event.recurrence === "FREQ=WEEKLY;BYDAY=MO" && start.getUTCDay() === 1 && start.getUTCHours() === 10) {
+    console.log(`PASS: Calendar event repeats ${event.recurrence} from ${intent.parameters.start_time}`);
+  } else {
+    console.error(`FAIL: Expected weekly Monday event at 10:00, got ${event.recurrence} from ${intent.parameters.start_time}`);
+    failures++;
+  }
+
+  // Adjective uses in the raw text don't make a one-off event recurring
+  const oneOff = normalizeIntent(
+    { type: "SCHEDULE", confidence: 0.95, parameters: { action: "create", temporal_expression: "tomorrow", title: "Weekly report review" } },
+    "schedule the weekly report review tomorrow",
+    "sim-v1",
+    "UTC"
+  );
+
+  if (oneOff.parameters.recurrence === undefined) {
+    console.log("PASS: \"the weekly report review tomorrow\" stays a one-off event");
+  } else {
+    console.error(`FAIL: Expected no recurrence, got ${oneOff.parameters.recurrence}`);
+    failures++;
+  }
+
+  if (failures > 0) {
+    process.exit(1);
+  }
+
+  console.log("PASS: Recurrence extracted as RRULE.");
+}
+
+runRecurrenceTest();
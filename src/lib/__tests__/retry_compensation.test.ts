@@ -0,0 +1,151 @@
+import { executePlan, ToolExecutor } from "../engine/orchestrator";
+import { getStepState } from "../engine/state-machine";
+import { makePlan, makeStep } from "./plan_fixtures";
+
+async function testRetryAndCompensation() {
+  console.log("--- TEST: Step Retry and Compensation ---");
+
+  // Test 1: A flaky step succeeds within its retry policy
+  console.log("Test 1: Retry with backoff...");
+  let flakyCalls = 0;
+  const flakyExecutor: ToolExecutor = {
+    execute: async () => {
+      flakyCalls++;
+      if (flakyCalls < 3) {
+        return { success: false, error: "network timeout", latency_ms: 1 };
+      }
+      return { success: true, output: { results: [] }, latency_ms: 1 };
+    },
+  };
+
+  const flakyStep = makeStep(0, "flaky_search", { retry_policy: { max_attempts: 3, backoff_ms: 10 } });
+  const retryResult = await executePlan(makePlan([flakyStep], "Retry test plan"), flakyExecutor, { persistState: false });
+  const flakyState = getStepState(retryResult.state, flakyStep.id);
+
+  if (retryResult.success && flakyCalls === 3 && flakyState?.attempts === 3) {
+    console.log("PASS: Step succeeded on third attempt.");
+  } else {
+    console.error(`FAIL: Expected success after 3 attempts, got success=${retryResult.success}, calls=${flakyCalls}, attempts=${flakyState?.attempts}`);
+    process.exit(1);
+  }
+
+  // Test 2: A mid-plan failure compensates completed steps in reverse order
+  console.log("Test 2: Compensation in reverse order...");
+  const calls: { name: string; params: Record<string, unknown> }[] = [];
+  const bookingExecutor: ToolExecutor = {
+    execute: async (name, params) => {
+      calls.push({ name, params });
+      switch (name) {
+        case "book_hotel":
+          return { success: true, output: { reservation_id: "H1" }, latency_ms: 1 };
+        case "book_flight":
+          return { success: true, output: { booking_ref: "F1" }, latency_ms: 1 };
+        case "charge_card":
+          return { success: false, error: "Card declined", latency_ms: 1 };
+        default:
+          return { success: true, output: { cancelled: true }, latency_ms: 1 };
+      }
+    },
+  };
+
+  const hotel = makeStep(0, "book_hotel");
+  hotel.compensation = { tool_name: "cancel_hotel", parameters: { reservation_id: `$${hotel.id}.reservation_id` } };
+  const flight = makeStep(1, "book_flight", { dependencies: [hotel.id] });
+  flight.compensation = { tool_name: "cancel_flight", parameters: { booking_ref: `$${flight.id}.booking_ref` } };
+  const charge = makeStep(2, "charge_card", { dependencies: [flight.id] });
+  const confirm = makeStep(3, "send_confirmation", { dependencies: [charge.id] });
+
+  const failResult = await executePlan(
+    makePlan([hotel, flight, charge, confirm], "Compensation test plan"),
+    bookingExecutor,
+    { persistState: false }
+  );
+
+  const compensations = calls.filter((c) => c.name.startsWith("cancel_"));
+  console.log(`Compensations: ${compensations.map((c) => c.name).join(" -> ")}`);
+
+  if (failResult.success) {
+    console.error("FAIL: Plan with a failed step should not succeed");
+    process.exit(1);
+  }
+
+  if (compensations.length !== 2 || compensations[0].name !== "cancel_flight" || compensations[1].name !== "cancel_hotel") {
+    console.error("FAIL: Expected cancel_flight then cancel_hotel");
+    process.exit(1);
+  }
+
+  if (compensations[1].params.reservation_id !== "H1" || compensations[0].params.booking_ref !== "F1") {
+    console.error(`FAIL: Compensation parameters not resolved from step outputs: ${JSON.stringify(compensations)}`);
+    process.exit(1);
+  }
+
+  if (getStepState(failResult.state, hotel.id)?.status !== "compensated" ||
+      getStepState(failResult.state, flight.id)?.status !== "compensated" ||
+      calls.some((c) => c.name === "send_confirmation")) {
+    console.error("FAIL: Completed steps should be marked compensated and dependents never run");
+    process.exit(1);
+  }
+
+  console.log("PASS: Completed steps compensated in reverse order.");
+
+  // Test 3: Parallel steps failing in the same round share one backoff wait
+  console.log("Test 3: Shared backoff for parallel retries...");
+  const attemptsByTool: Record<string, number> = {};
+  const parallelFlakyExecutor: ToolExecutor = {
+    execute: async (name) => {
+      attemptsByTool[name] = (attemptsByTool[name] || 0) + 1;
+      if (attemptsByTool[name] === 1) {
+        return { success: false, error: "network timeout", latency_ms: 1 };
+      }
+      return { success: true, output: { results: [] }, latency_ms: 1 };
+    },
+  };
+
+  const retryPolicy = { max_attempts: 2, backoff_ms: 300 };
+  const parallelSteps = [
+    makeStep(0, "flaky_a", { retry_policy: retryPolicy }),
+    makeStep(1, "flaky_b", { retry_policy: retryPolicy }),
+    makeStep(2, "flaky_c", { retry_policy: retryPolicy }),
+  ];
+
+  const parallelStart = Date.now();
+  const parallelResult = await executePlan(makePlan(parallelSteps, "Parallel retry test plan"), parallelFlakyExecutor, { persistState: false });
+  const parallelElapsed = Date.now() - parallelStart;
+
+  if (!parallelResult.success || parallelElapsed >= 600) {
+    console.error(`FAIL: Expected one shared 300ms backoff, got success=${parallelResult.success} in ${parallelElapsed}ms`);
+    process.exit(1);
+  }
+
+  console.log(`PASS: Three parallel retries completed in ${parallelElapsed}ms.`);
+
+  // Test 4: A rate-limited step waits out the tool's retry-after rather than its shorter backoff
+  console.log("Test 4: Retry honours retry_after_ms...");
+  let limitedCalls = 0;
+  const rateLimitedExecutor: ToolExecutor = {
+    execute: async () => {
+      limitedCalls++;
+      if (limitedCalls === 1) {
+        return { success: false, error: "Rate limit exceeded; retry after 300ms", latency_ms: 1, retry_after_ms: 300 };
+      }
+      return { success: true, output: { results: [] }, latency_ms: 1 };
+    },
+  };
+
+  const limitedStep = makeStep(0, "limited_search", { retry_policy: { max_attempts: 2, backoff_ms: 10 } });
+  const limitedStart = Date.now();
+  const limitedResult = await executePlan(makePlan([limitedStep], "Rate limit retry test plan"), rateLimitedExecutor, { persistState: false });
+  const limitedElapsed = Date.now() - limitedStart;
+
+  if (!limitedResult.success || limitedCalls !== 2 || limitedElapsed < 280) {
+    console.error(`FAIL: Expected the retry after ~300ms, got success=${limitedResult.success}, calls=${limitedCalls} in ${limitedElapsed}ms`);
+    process.exit(1);
+  }
+  console.log(`PASS: Retried after the tool's retry-after (${limitedElapsed}ms).`);
+  console.log("--- ALL TESTS PASSED ---");
+}
+
+testRetryAndCompensation().catch(err => {
+  console.error(err);
+  process.exit(1);
+});
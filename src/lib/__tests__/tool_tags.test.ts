@@ -0,0 +1,30 @@
+import { getToolsByTag } from "../tools/registry";
+
+async function runToolTagsTest() {
+  console.log("--- TOOL TAG DISCOVERY TEST ---");
+
+  const reservation = getToolsByTag("reservation").map(t => t.name).sort();
+  console.log(`Reservation tools: ${reservation.join(", ")}`);
+
+  if (JSON.stringify(reservation) !== JSON.stringify(["book_restaurant_table", "reserve_restaurant"])) {
+    console.error("FAIL: Expected book_restaurant_table and reserve_restaurant to be tagged reservation");
+    process.exit(1);
+  }
+
+  const transportation = getToolsByTag("Transportation").map(t => t.name);
+  console.log(`Transportation tools: ${transportation.join(", ")}`);
+
+  if (!transportation.includes("request_ride") || !transportation.includes("get_route_estimate")) {
+    console.error("FAIL: Tag lookup should be case-insensitive and include ride tools");
+    process.exit(1);
+  }
+
+  if (getToolsByTag("teleportation").length !== 0) {
+    console.error("FAIL: Unknown tags should match nothing");
+    process.exit(1);
+  }
+
+  console.log("PASS: Tools discoverable by domain tag.");
+}
+
+runToolTagsTest();
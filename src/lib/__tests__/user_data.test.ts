@@ -0,0 +1,118 @@
+import { redis } from "../redis-client";
+import { createAuditLog, deleteAuditLog, getAuditLog } from "../audit";
+import { exportUserData, eraseUserData, authorizeUserDataRequest } from "../user-data";
+
+// In-memory stand-in for the Redis commands used by audit logs and preferences
+function stubRedis() {
+  const values = new Map<string, unknown>();
+  const lists = new Map<string, string[]>();
+  const stub = redis as any;
+
+  stub.get = async (key: string) => values.get(key) ?? null;
+  stub.set = async (key: string, value: unknown) => { values.set(key, value); return "OK"; };
+  stub.del = async (...keys: string[]) =>
+    keys.filter((key) => values.delete(key) || lists.delete(key)).length;
+  stub.lpush = async (key: string, value: string) => {
+    lists.set(key, [value, ...(lists.get(key) || [])]);
+    return lists.get(key)!.length;
+  };
+  stub.ltrim = async (key: string, start: number, stop: number) => {
+    lists.set(key, (lists.get(key) || []).slice(start, stop + 1));
+    return "OK";
+  };
+  stub.lrange = async (key: string, start: number, stop: number) =>
+    (lists.get(key) || []).slice(start, stop === -1 ? undefined : stop + 1);
+  stub.expire = async () => 1;
+  stub.lrem = async (key: string, _count: number, value: string) => {
+    const list = lists.get(key) || [];
+    lists.set(key, list.filter((v) => v !== value));
+    return list.length - lists.get(key)!.length;
+  };
+}
+
+function makeIntent(rawText: string): any {
+  return { id: crypto.randomUUID(), type: "SEARCH", parameters: {}, rawText };
+}
+
+async function runUserDataTest() {
+  console.log("--- USER DATA EXPORT AND ERASURE TEST ---");
+  stubRedis();
+
+  const aliceFirst = await createAuditLog(makeIntent("find sushi"), undefined, undefined, "alice");
+  const aliceSecond = await createAuditLog(makeIntent("book a table"), undefined, undefined, "alice");
+  const bobLog = await createAuditLog(makeIntent("call a ride"), undefined, undefined, "bob");
+  await redis.set("prefs:alice", { preferredCuisines: ["sushi"] });
+
+  // Export includes preferences and every audit log, and nothing of other users
+  const exported = await exportUserData("alice");
+  const exportedIds = exported.audit_logs.map((log) => log.id).sort();
+  if (
+    JSON.stringify(exported.preferences) !== JSON.stringify({ preferredCuisines: ["sushi"] }) ||
+    JSON.stringify(exportedIds) !== JSON.stringify([aliceFirst.id, aliceSecond.id].sort())
+  ) {
+    console.error(`FAIL: Incomplete export ${JSON.stringify(exported)}`);
+    process.exit(1);
+  }
+  console.log("PASS: Export contains the user's preferences and audit history.");
+
+  // A user cannot delete another user's audit log
+  if (await deleteAuditLog("alice", bobLog.id) || !(await getAuditLog(bobLog.id))) {
+    console.error("FAIL: Another user's audit log was deleted");
+    process.exit(1);
+  }
+  console.log("PASS: Another user's audit log cannot be deleted.");
+
+  if (!(await deleteAuditLog("alice", aliceFirst.id)) || await getAuditLog(aliceFirst.id)) {
+    console.error("FAIL: The user's own audit log should be deleted");
+    process.exit(1);
+  }
+  console.log("PASS: The user's own audit log is deleted.");
+
+  // Erasure leaves an empty but valid export and other users untouched
+  const erased = await eraseUserData("alice");
+  const afterErase = await exportUserData("alice");
+  if (
+    !erased.preferences_deleted || erased.audit_logs_deleted !== 1 ||
+    JSON.stringify(afterErase.preferences) !== "{}" || afterErase.audit_logs.length !== 0 ||
+    afterErase.user_id !== "alice" || !(await getAuditLog(bobLog.id))
+  ) {
+    console.error(`FAIL: Unexpected erase result ${JSON.stringify({ erased, afterErase })}`);
+    process.exit(1);
+  }
+  console.log("PASS: Erasure removes everything stored for the user only.");
+
+  // A long history is exported and erased in full, not just the newest entries
+  const carolLogs = [];
+  for (let i = 0; i < 25; i++) {
+    carolLogs.push(await createAuditLog(makeIntent(`request ${i}`), undefined, { lat: 37.77, lng: -122.42 }, "carol"));
+  }
+  const carolExport = await exportUserData("carol");
+  const carolErased = await eraseUserData("carol");
+  if (
+    carolExport.audit_logs.length !== 25 || carolErased.audit_logs_deleted !== 25 ||
+    await getAuditLog(carolLogs[0].id) || (await exportUserData("carol")).audit_logs.length !== 0
+  ) {
+    console.error(`FAIL: Expected all 25 logs exported and erased, got ${carolExport.audit_logs.length} / ${carolErased.audit_logs_deleted}`);
+    process.exit(1);
+  }
+  console.log("PASS: More than 20 logs are exported and erased in full.");
+
+  // Requests need credentials and an explicit, non-anonymous user id
+  process.env.INTERNAL_SYSTEM_KEY = "test-internal-key";
+  const request = (headers: Record<string, string>) => new Request("http://localhost/api/user-data", { headers });
+
+  const unauthenticated = await authorizeUserDataRequest(request({ "x-user-id": "alice", "x-forwarded-for": "alice" }));
+  const anonymous = await authorizeUserDataRequest(request({ "x-api-key": "test-internal-key", "x-user-id": "anonymous" }));
+  const authorized = await authorizeUserDataRequest(request({ "x-api-key": "test-internal-key", "x-user-id": "alice" }));
+
+  if (unauthenticated.status !== 401 || anonymous.status !== 400 || authorized.userId !== "alice") {
+    console.error(`FAIL: Unexpected authorization ${JSON.stringify({ unauthenticated, anonymous, authorized })}`);
+    process.exit(1);
+  }
+  console.log("PASS: Only authenticated requests for a named user are authorized.");
+}
+
+runUserDataTest().catch(err => {
+  console.error(err);
+  process.exit(1);
+});
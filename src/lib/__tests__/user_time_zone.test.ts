@@ -0,0 +1,59 @@
+import { inferIntent } from "../intent";
+import { add_calendar_event } from "../tools/calendar";
+
+// Stands in for the model: a single SCHEDULE candidate carrying the user's own wording
+async function scheduleCandidate(_system: string, prompt: string) {
+  return {
+    candidates: [{
+      type: "SCHEDULE",
+      confidence: 0.95,
+      parameters: { action: "create", temporal_expression: prompt, title: "Dentist" },
+      explanation: "IDENTIFY: appointment. MAP: SCHEDULE.",
+    }],
+  };
+}
+
+function wallClockHour(iso: string, timeZone: string): string {
+  return new Intl.DateTimeFormat("en-US", { timeZone, hour: "2-digit", hourCycle: "h23" }).format(new Date(iso));
+}
+
+async function runUserTimeZoneTest() {
+  console.log("--- USER TIME ZONE TEST ---");
+
+  // Test 1: Inference resolves relative times on the user's clock
+  const inZone = async (timeZone?: string) => {
+    const { hypotheses } = await inferIntent("tomorrow at 3pm", [], [], {}, {
+      timeZone,
+      generateCandidates: scheduleCandidate,
+    });
+    return hypotheses.primary.parameters.start_time as string;
+  };
+
+  const tokyoStart = await inZone("Asia/Tokyo");
+  const newYorkStart = await inZone("America/New_York");
+  console.log(`Tokyo: ${tokyoStart}, New York: ${newYorkStart}`);
+
+  if (wallClockHour(tokyoStart, "Asia/Tokyo") !== "15" || wallClockHour(newYorkStart, "America/New_York") !== "15") {
+    console.error("FAIL: \"tomorrow at 3pm\" should be 3pm on the user's own clock");
+    process.exit(1);
+  }
+  console.log("PASS: inferIntent resolves \"tomorrow at 3pm\" in the user's time zone.");
+
+  // Test 2: The calendar download link carries the zone to the ICS route
+  const calendar = await add_calendar_event({
+    events: [{ title: "Dentist", start_time: "tomorrow at 3pm", end_time: "tomorrow at 4pm" }],
+    timezone: "Asia/Tokyo",
+  });
+  const downloadUrl = calendar.success ? calendar.result.download_url : "";
+
+  if (new URL(downloadUrl, "http://localhost").searchParams.get("timezone") !== "Asia/Tokyo") {
+    console.error(`FAIL: Expected the download URL to carry the time zone, got ${JSON.stringify(calendar)}`);
+    process.exit(1);
+  }
+  console.log("PASS: Calendar download URL carries the user's time zone.");
+}
+
+runUserTimeZoneTest().catch(err => {
+  console.error(err);
+  process.exit(1);
+});
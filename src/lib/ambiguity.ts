@@ -1,4 +1,5 @@
 import type { Intent } from "./schema";
+import { generateClarificationQuestion } from "./resolveAmbiguity";
 
 export interface IntentHypotheses {
   primary: Intent;
@@ -7,18 +8,33 @@ export interface IntentHypotheses {
   clarificationQuestion?: string;
 }
 
-const CONFIDENCE_THRESHOLD = 0.85;
-const AMBIGUITY_GAP = 0.15;
+export interface AmbiguityThresholds {
+  /** Minimum confidence for the top intent to proceed without clarification */
+  confidence: number;
+  /** Minimum confidence lead the top intent needs over the runner-up */
+  gap: number;
+}
+
+export const DEFAULT_AMBIGUITY_THRESHOLDS: AmbiguityThresholds = {
+  confidence: 0.85,
+  gap: 0.15,
+};
 
 /**
  * Evaluates a set of normalized intents to determine if there is a clear winner
- * or if the situation is ambiguous.
+ * or if the situation is ambiguous. Thresholds default to DEFAULT_AMBIGUITY_THRESHOLDS
+ * and can be overridden per call.
  */
-export function resolveAmbiguity(intents: Intent[]): IntentHypotheses {
+export function resolveAmbiguity(
+  intents: Intent[],
+  thresholds: Partial<AmbiguityThresholds> = {}
+): IntentHypotheses {
   if (intents.length === 0) {
     throw new Error("No intents provided for resolution");
   }
 
+  const { confidence: confidenceThreshold, gap: ambiguityGap } = { ...DEFAULT_AMBIGUITY_THRESHOLDS, ...thresholds };
+
   // Sort by confidence descending
   const sorted = [...intents].sort((a, b) => b.confidence - a.confidence);
   const primary = sorted[0];
@@ -28,13 +44,16 @@ export function resolveAmbiguity(intents: Intent[]): IntentHypotheses {
   let clarificationQuestion: string | undefined;
 
   // Rule 1: Low Absolute Confidence
-  if (primary.confidence < CONFIDENCE_THRESHOLD) {
+  if (primary.confidence < confidenceThreshold) {
     isAmbiguous = true;
-    clarificationQuestion = primary.explanation || "I'm not sure what you want to do. Could you clarify?";
+    clarificationQuestion = primary.parameters.clarificationQuestion
+      || generateClarificationQuestion(primary.type, primary.parameters)
+      || primary.explanation
+      || "I'm not sure what you want to do. Could you clarify?";
   }
 
   // Rule 2: Narrow Gap between Top 2
-  if (alternatives.length > 0 && (primary.confidence - alternatives[0].confidence) < AMBIGUITY_GAP) {
+  if (alternatives.length > 0 && (primary.confidence - alternatives[0].confidence) < ambiguityGap) {
     isAmbiguous = true;
     clarificationQuestion = `I'm torn between ${primary.type} and ${alternatives[0].type}. Which did you mean?`;
   }
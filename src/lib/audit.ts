@@ -5,6 +5,7 @@ import type { AuditLog } from "./types";
 
 const AUDIT_LOG_PREFIX = "audit_log:";
 const USER_LOGS_PREFIX = "user_logs:";
+const AUDIT_LOG_TTL_SECONDS = 86400 * 7; // Store for 7 days
 
 /**
  * Calculates a SHA-256 hash of the intent's core content for cryptographic linking.
@@ -51,12 +52,13 @@ export async function createAuditLog(
   };
 
   if (redis) {
-    await redis.set(`${AUDIT_LOG_PREFIX}${id}`, JSON.stringify(log), { ex: 86400 * 7 }); // Store for 7 days
+    await redis.set(`${AUDIT_LOG_PREFIX}${id}`, JSON.stringify(log), { ex: AUDIT_LOG_TTL_SECONDS });
     
-    // Track logs for this user
+    // Track logs for this user. The index is never trimmed and lives as long as its newest log,
+    // so every stored log stays reachable for export and erasure.
     try {
       await redis.lpush(`${USER_LOGS_PREFIX}${userId}`, id);
-      await redis.ltrim(`${USER_LOGS_PREFIX}${userId}`, 0, 19); // Keep last 20 logs
+      await redis.expire(`${USER_LOGS_PREFIX}${userId}`, AUDIT_LOG_TTL_SECONDS);
     } catch (err) {
       console.warn("Failed to update user logs index:", err);
     }
@@ -67,11 +69,14 @@ export async function createAuditLog(
   return log;
 }
 
+/**
+ * Returns a user's most recent audit logs, newest first. Pass Infinity for the full history.
+ */
 export async function getUserAuditLogs(userId: string, limit: number = 5): Promise<AuditLog[]> {
   if (!redis) return [];
 
   try {
-    const ids = await redis.lrange(`${USER_LOGS_PREFIX}${userId}`, 0, limit - 1);
+    const ids = await redis.lrange(`${USER_LOGS_PREFIX}${userId}`, 0, Number.isFinite(limit) ? limit - 1 : -1);
     if (!ids || ids.length === 0) return [];
 
     const logs = await Promise.all(ids.map(id => getAuditLog(id)));
@@ -87,7 +92,7 @@ export async function updateAuditLog(id: string, update: Partial<AuditLog>): Pro
     const existing = await getAuditLog(id);
     if (existing) {
       const updated = { ...existing, ...update };
-      await redis.set(`${AUDIT_LOG_PREFIX}${id}`, JSON.stringify(updated), { ex: 86400 * 7 });
+      await redis.set(`${AUDIT_LOG_PREFIX}${id}`, JSON.stringify(updated), { ex: AUDIT_LOG_TTL_SECONDS });
     }
   }
 }
@@ -124,6 +129,34 @@ export async function supersedeIntent(
   };
 
   if (redis) {
-    await redis.set(`${AUDIT_LOG_PREFIX}${auditLogId}`, JSON.stringify(updatedLog), { ex: 86400 * 7 });
+    await redis.set(`${AUDIT_LOG_PREFIX}${auditLogId}`, JSON.stringify(updatedLog), { ex: AUDIT_LOG_TTL_SECONDS });
   }
 }
+
+/**
+ * Deletes a single audit log and removes it from the user's index.
+ * Returns false if the log does not belong to the user.
+ */
+export async function deleteAuditLog(userId: string, id: string): Promise<boolean> {
+  if (!redis) return false;
+
+  const removed = await redis.lrem(`${USER_LOGS_PREFIX}${userId}`, 0, id);
+  if (!removed) return false;
+
+  await redis.del(`${AUDIT_LOG_PREFIX}${id}`);
+  return true;
+}
+
+/**
+ * Deletes every audit log indexed for a user, along with the index itself.
+ * Returns the number of logs removed.
+ */
+export async function eraseUserAuditLogs(userId: string): Promise<number> {
+  if (!redis) return 0;
+
+  const ids: string[] = await redis.lrange(`${USER_LOGS_PREFIX}${userId}`, 0, -1);
+  // Logs past their TTL may still be indexed, so count what was actually deleted
+  const deleted = ids.length > 0 ? await redis.del(...ids.map(id => `${AUDIT_LOG_PREFIX}${id}`)) : 0;
+  await redis.del(`${USER_LOGS_PREFIX}${userId}`);
+  return deleted;
+}
@@ -30,6 +30,96 @@ export async function verifyServiceToken(token: string) {
   }
 }
 
+const APPROVAL_ISSUER = 'intention-engine-approval';
+const APPROVAL_SCOPE = 'plan:execute';
+
+/**
+ * Computes a SHA-256 digest of a plan's executable content (tools and parameters),
+ * so an approval cannot be replayed against a modified plan.
+ */
+export async function hashPlan(plan: { id: string; steps: Array<{ tool_name: string; parameters: unknown }> }): Promise<string> {
+  const content = JSON.stringify({
+    id: plan.id,
+    steps: plan.steps.map(step => ({ tool_name: step.tool_name, parameters: step.parameters })),
+  });
+
+  const hashBuffer = await crypto.subtle.digest('SHA-256', new TextEncoder().encode(content));
+  return Array.from(new Uint8Array(hashBuffer)).map(b => b.toString(16).padStart(2, '0')).join('');
+}
+
+/**
+ * The paused execution and the steps within it that an approval covers.
+ */
+export interface ApprovalBinding {
+  execution_id: string;
+  step_ids: string[];
+}
+
+function sameStepIds(a: unknown, b: string[]): boolean {
+  return Array.isArray(a) && a.length === b.length && [...b].sort().every((id, i) => id === a[i]);
+}
+
+/**
+ * Issues a time-limited, single-use approval token bound to the exact plan the user approved
+ * and, when given, to the execution and steps that were paused for it.
+ */
+export async function signApprovalToken(
+  plan: { id: string; steps: Array<{ tool_name: string; parameters: unknown }> },
+  ttlSeconds: number = 900,
+  binding?: ApprovalBinding
+) {
+  const claims: jose.JWTPayload = { plan_hash: await hashPlan(plan), scope: APPROVAL_SCOPE };
+  if (binding) {
+    claims.execution_id = binding.execution_id;
+    claims.step_ids = [...binding.step_ids].sort();
+  }
+
+  return await new jose.SignJWT(claims)
+    .setProtectedHeader({ alg: 'HS256' })
+    .setIssuedAt()
+    .setSubject(plan.id)
+    .setJti(crypto.randomUUID())
+    .setExpirationTime(Math.floor(Date.now() / 1000) + ttlSeconds)
+    .setIssuer(APPROVAL_ISSUER)
+    .sign(secret);
+}
+
+/**
+ * Verifies an approval token against the plan about to be executed, and against the paused
+ * execution and steps when a binding is given.
+ * Rejects expired, tampered, or foreign tokens and tokens issued for a different plan.
+ * The returned jti and expiry let the caller consume the token so it is only honoured once.
+ */
+export async function verifyApprovalToken(
+  token: string,
+  plan: { id: string; steps: Array<{ tool_name: string; parameters: unknown }> },
+  binding?: ApprovalBinding
+): Promise<{ valid: boolean; reason?: string; jti?: string; expires_at?: number }> {
+  let payload: jose.JWTPayload;
+  try {
+    ({ payload } = await jose.jwtVerify(token, secret, {
+      issuer: APPROVAL_ISSUER,
+      algorithms: ['HS256'],
+    }));
+  } catch (e: any) {
+    return { valid: false, reason: e?.code === 'ERR_JWT_EXPIRED' ? 'Approval token expired' : 'Invalid approval token' };
+  }
+
+  if (payload.scope !== APPROVAL_SCOPE) {
+    return { valid: false, reason: 'Approval token has wrong scope' };
+  }
+
+  if (payload.sub !== plan.id || payload.plan_hash !== await hashPlan(plan)) {
+    return { valid: false, reason: 'Approval token was issued for a different plan' };
+  }
+
+  if (binding && (payload.execution_id !== binding.execution_id || !sameStepIds(payload.step_ids, binding.step_ids))) {
+    return { valid: false, reason: 'Approval token was issued for different steps' };
+  }
+
+  return { valid: true, jti: payload.jti, expires_at: payload.exp };
+}
+
 /**
  * Validates the API key or JWT service token.
  */
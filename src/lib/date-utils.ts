@@ -7,18 +7,26 @@ const customOpenAI = createOpenAI({
   baseURL: env.LLM_BASE_URL,
 });
 
-export async function parseNaturalLanguageDate(dateStr: string): Promise<Date> {
+/**
+ * Parses a date string, reading relative expressions in the user's IANA time zone
+ * (the server's zone when omitted).
+ */
+export async function parseNaturalLanguageDate(dateStr: string, timeZone?: string): Promise<Date> {
   // Try native Date parsing first for ISO strings
   const d = new Date(dateStr);
   if (!isNaN(d.getTime()) && dateStr.includes("-")) return d;
 
+  // Resolve common relative expressions deterministically before falling back to the LLM
+  const resolved = resolveRelativeTime(dateStr, new Date(), timeZone);
+  if (resolved) return new Date(resolved);
+
   // Use LLM for natural language dates
   try {
     const { text } = await generateText({
       model: customOpenAI(env.LLM_MODEL),
       system: `You are a date parsing utility. 
 Convert the user's natural language date string into an ISO 8601 timestamp.
-Current time: ${new Date().toISOString()}
+Current time: ${new Date().toISOString()}${timeZone ? `\nUser time zone: ${timeZone}` : ""}
 Target format: YYYY-MM-DDTHH:mm:ssZ
 
 Return ONLY the ISO string.`,
@@ -34,31 +42,378 @@ Return ONLY the ISO string.`,
   }
 
   // Fallback to basic logic for common cases if LLM fails
-  return basicParseDateTime(dateStr);
+  return basicParseDateTime(dateStr, timeZone);
 }
 
-function basicParseDateTime(dt: string): Date {
+function basicParseDateTime(dt: string, timeZone?: string): Date {
   const d = new Date(dt);
   if (!isNaN(d.getTime())) return d;
-  
-  if (dt.toLowerCase().includes("tomorrow")) {
-    const now = new Date();
-    const tomorrow = new Date(now);
-    tomorrow.setDate(now.getDate() + 1);
-    
-    const timeMatch = dt.match(/(\d+)(?::(\d+))?\s*(am|pm)?/i);
-    if (timeMatch) {
-      let hours = parseInt(timeMatch[1]);
-      const minutes = parseInt(timeMatch[2] || "0");
-      const ampm = (timeMatch[3] || "").toLowerCase();
-      
-      if (ampm === "pm" && hours < 12) hours += 12;
-      if (ampm === "am" && hours === 12) hours = 0;
-      
-      tomorrow.setHours(hours, minutes, 0, 0);
-      return tomorrow;
+
+  const resolved = resolveRelativeTime(dt, new Date(), timeZone);
+  return resolved ? new Date(resolved) : d;
+}
+
+const WEEKDAYS = ["sunday", "monday", "tuesday", "wednesday", "thursday", "friday", "saturday"];
+
+const DAYPART_HOURS: Record<string, number> = {
+  morning: 9,
+  noon: 12,
+  afternoon: 14,
+  evening: 19,
+  tonight: 20,
+  night: 20,
+};
+
+const DAYPART_PATTERNS = Object.keys(DAYPART_HOURS).map((part) => ({
+  part,
+  pattern: new RegExp(`\\b${part}\\b`),
+}));
+
+const UNIT_MS: Record<string, number> = {
+  minute: 60 * 1000,
+  hour: 60 * 60 * 1000,
+  day: 24 * 60 * 60 * 1000,
+  week: 7 * 24 * 60 * 60 * 1000,
+};
+
+function toIsoString(date: Date): string {
+  return date.toISOString().replace(/\.\d{3}Z$/, "Z");
+}
+
+const HOUR_WORDS: Record<string, number> = {
+  one: 1, two: 2, three: 3, four: 4, five: 5, six: 6,
+  seven: 7, eight: 8, nine: 9, ten: 10, eleven: 11, twelve: 12,
+};
+const HOUR_TOKEN = `(\\d{1,2}|${Object.keys(HOUR_WORDS).join("|")})`;
+
+const RELATIVE_CLOCK_PATTERN = new RegExp(`\\b(half|quarter)\\s+(past|to)\\s+${HOUR_TOKEN}\\b(?:\\s*(am|pm)\\b)?`);
+const OCLOCK_PATTERN = new RegExp(`\\b${HOUR_TOKEN}\\s*o['’]?\\s*clock\\b(?:\\s*(am|pm)\\b)?`);
+const AMPM_PATTERN = new RegExp(`\\b${HOUR_TOKEN}(?::(\\d{2}))?\\s*(am|pm)\\b`);
+const MILITARY_PATTERN = /\b([01]\d|2[0-3])([0-5]\d)\s*(?:hours|hrs)\b/;
+
+function parseHourToken(token: string): number {
+  return HOUR_WORDS[token] ?? parseInt(token);
+}
+
+/**
+ * Converts a 12-hour clock hour to 24-hour time. Without a meridiem the hour is returned as-is.
+ */
+function to24Hour(hours: number, meridiem: string | undefined): number {
+  if (meridiem === "pm" && hours < 12) return hours + 12;
+  if (meridiem === "am" && hours === 12) return 0;
+  return hours;
+}
+
+/**
+ * Extracts a clock time such as "7pm", "7:30 pm", "19:30", "1400 hours",
+ * "seven o'clock", "half past six" or "quarter to seven" from free text.
+ * Spelled-out forms take am/pm from a suffix or phrases like "in the evening";
+ * when neither is present the hour is left as stated.
+ * A dayOffset of -1 marks a time that falls on the day before, as in "quarter to 12 am".
+ */
+export function parseClockTime(input: string): { hours: number; minutes: number; dayOffset?: number } | undefined {
+  const text = input.toLowerCase();
+
+  const meridiemHint = /\b(in the (afternoon|evening)|at night|tonight)\b/.test(text)
+    ? "pm"
+    : /\bin the morning\b/.test(text) ? "am" : undefined;
+
+  const relativeMatch = text.match(RELATIVE_CLOCK_PATTERN);
+  if (relativeMatch) {
+    const hour = parseHourToken(relativeMatch[3]);
+    if (hour < 1 || hour > 12) return undefined;
+    const meridiem = relativeMatch[4] || meridiemHint;
+    if (relativeMatch[2] === "to") {
+      // The meridiem belongs to the hour being approached: "quarter to twelve pm" is 11:45,
+      // "quarter to 12 am" is 23:45 the day before, and a bare "quarter to one" is 12:45
+      const target = to24Hour(hour, meridiem);
+      if (target === 0) return { hours: 23, minutes: 45, dayOffset: -1 };
+      return { hours: target === 1 && !meridiem ? 12 : target - 1, minutes: 45 };
+    }
+    return { hours: to24Hour(hour, meridiem), minutes: relativeMatch[1] === "half" ? 30 : 15 };
+  }
+
+  const oclockMatch = text.match(OCLOCK_PATTERN);
+  if (oclockMatch) {
+    const hour = parseHourToken(oclockMatch[1]);
+    if (hour > 23) return undefined;
+    return { hours: hour > 12 ? hour : to24Hour(hour, oclockMatch[2] || meridiemHint), minutes: 0 };
+  }
+
+  const ampmMatch = text.match(AMPM_PATTERN);
+  if (ampmMatch) {
+    const hours = parseHourToken(ampmMatch[1]);
+    const minutes = parseInt(ampmMatch[2] || "0");
+    if (hours < 1 || hours > 12 || minutes > 59) return undefined;
+    return { hours: to24Hour(hours, ampmMatch[3]), minutes };
+  }
+
+  const militaryMatch = text.match(MILITARY_PATTERN);
+  if (militaryMatch) {
+    return { hours: parseInt(militaryMatch[1]), minutes: parseInt(militaryMatch[2]) };
+  }
+
+  const clockMatch = text.match(/\b(\d{1,2}):(\d{2})\b/);
+  if (clockMatch) {
+    const hours = parseInt(clockMatch[1]);
+    const minutes = parseInt(clockMatch[2]);
+    if (hours > 23 || minutes > 59) return undefined;
+    return { hours, minutes };
+  }
+
+  return undefined;
+}
+
+const OFFSET_PATTERN = /\bin\s+(\d+|an?|one)\s+(minute|hour|day|week)s?\b/;
+const DAY_REFERENCE_PATTERN = /\b(day after tomorrow|tomorrow|today|tonight)\b/;
+const WEEKDAY_PATTERN = new RegExp(`\\b(${WEEKDAYS.join("|")})\\b`);
+
+// Everything resolveRelativeTime understands. Words left over once these are removed
+// ("March 3rd", "the second Friday of") mean the heuristic would only see part of the date.
+const RECOGNIZED_PATTERNS = [
+  RELATIVE_CLOCK_PATTERN,
+  OCLOCK_PATTERN,
+  AMPM_PATTERN,
+  MILITARY_PATTERN,
+  /\b\d{1,2}:\d{2}\b/,
+  /\bfor\s+(\d+(?:\.\d+)?|half an?|an?|one)[\s-]*(hours?|hrs?|minutes?|mins?)\b/,
+  OFFSET_PATTERN,
+  DAY_REFERENCE_PATTERN,
+  WEEKDAY_PATTERN,
+  ...DAYPART_PATTERNS.map(({ pattern }) => pattern),
+  /\b(at|on|in|this|next|coming|the|around|about|by|every|each)\b/,
+].map((pattern) => new RegExp(pattern.source, "g"));
+
+function isFullyRecognized(text: string): boolean {
+  const rest = RECOGNIZED_PATTERNS.reduce((remaining, pattern) => remaining.replace(pattern, " "), text);
+  return !/[a-z0-9]/.test(rest);
+}
+
+const SYSTEM_TIME_ZONE = Intl.DateTimeFormat().resolvedOptions().timeZone;
+const zoneFormatters = new Map<string, Intl.DateTimeFormat>();
+
+/**
+ * Milliseconds by which a time zone's wall clock is ahead of UTC at the given instant.
+ */
+function timeZoneOffsetMs(instant: Date, timeZone: string): number {
+  let formatter = zoneFormatters.get(timeZone);
+  if (!formatter) {
+    formatter = new Intl.DateTimeFormat("en-US", {
+      timeZone,
+      hourCycle: "h23",
+      year: "numeric",
+      month: "numeric",
+      day: "numeric",
+      hour: "numeric",
+      minute: "numeric",
+      second: "numeric",
+    });
+    zoneFormatters.set(timeZone, formatter);
+  }
+
+  const parts = formatter.formatToParts(instant);
+  const field = (type: string) => parseInt(parts.find((part) => part.type === type)?.value || "0");
+  const wallAsUtc = Date.UTC(field("year"), field("month") - 1, field("day"), field("hour"), field("minute"), field("second"));
+  return wallAsUtc - Math.floor(instant.getTime() / 1000) * 1000;
+}
+
+/**
+ * Checks that a string names an IANA time zone the runtime knows, e.g. "America/New_York".
+ */
+export function isValidTimeZone(timeZone: string): boolean {
+  try {
+    new Intl.DateTimeFormat("en-US", { timeZone });
+    return true;
+  } catch {
+    return false;
+  }
+}
+
+/**
+ * Converts a wall-clock time (held in the UTC fields of `wall`) in a time zone back to an instant.
+ * The offset is re-read at the target so times across a DST change land correctly.
+ */
+function fromWallClock(wall: Date, timeZone: string): Date {
+  const guess = new Date(wall.getTime() - timeZoneOffsetMs(wall, timeZone));
+  return new Date(wall.getTime() - timeZoneOffsetMs(guess, timeZone));
+}
+
+/**
+ * Deterministically resolves relative date expressions ("tomorrow at 3pm",
+ * "next Friday evening", "in 2 hours", "tonight") into an ISO 8601 timestamp.
+ * Days and times are read on the wall clock of `timeZone` (an IANA name, defaulting
+ * to the server's zone) relative to `now`. Returns undefined unless the whole input
+ * is a date or time reference the heuristic understands, so "March 3rd at 7pm"
+ * is left for a fuller parser rather than resolved to today at 7pm.
+ */
+export function resolveRelativeTime(input: string, now: Date = new Date(), timeZone: string = SYSTEM_TIME_ZONE): string | undefined {
+  const text = input.toLowerCase();
+
+  // Absolute dates pass through unchanged
+  const direct = new Date(input);
+  if (!isNaN(direct.getTime()) && /\d{4}-\d{2}-\d{2}/.test(input)) {
+    return toIsoString(direct);
+  }
+
+  if (!isFullyRecognized(text)) return undefined;
+
+  const wallNow = new Date(now.getTime() + timeZoneOffsetMs(now, timeZone));
+
+  // Offsets from now ("in 2 hours", "in an hour", "in 3 days")
+  const offsetMatch = text.match(OFFSET_PATTERN);
+  if (offsetMatch) {
+    const amount = /^\d+$/.test(offsetMatch[1]) ? parseInt(offsetMatch[1]) : 1;
+
+    // "in 2 days at 3pm" pins the time of day; sub-day offsets keep the exact instant
+    const clock = parseClockTime(text);
+    if (clock && (offsetMatch[2] === "day" || offsetMatch[2] === "week")) {
+      const wall = new Date(wallNow.getTime() + amount * UNIT_MS[offsetMatch[2]]);
+      wall.setUTCDate(wall.getUTCDate() + (clock.dayOffset || 0));
+      wall.setUTCHours(clock.hours, clock.minutes, 0, 0);
+      return toIsoString(fromWallClock(wall, timeZone));
+    }
+
+    const result = new Date(now.getTime() + amount * UNIT_MS[offsetMatch[2]]);
+    result.setUTCSeconds(0, 0);
+    return toIsoString(result);
+  }
+
+  // Day reference
+  let dayOffset: number | undefined;
+  const dayMatch = text.match(DAY_REFERENCE_PATTERN);
+  if (dayMatch) {
+    dayOffset = dayMatch[1] === "day after tomorrow" ? 2 : dayMatch[1] === "tomorrow" ? 1 : 0;
+  } else {
+    const weekdayMatch = text.match(WEEKDAY_PATTERN);
+    if (weekdayMatch) {
+      const target = WEEKDAYS.indexOf(weekdayMatch[1]);
+      dayOffset = (target - wallNow.getUTCDay() + 7) % 7 || 7;
     }
-    return tomorrow;
   }
-  return d;
+
+  // Time of day: explicit clock time wins over a daypart
+  let time = parseClockTime(text);
+  if (!time) {
+    const daypart = DAYPART_PATTERNS.find(({ pattern }) => pattern.test(text));
+    if (daypart) {
+      time = { hours: DAYPART_HOURS[daypart.part], minutes: 0 };
+    }
+  }
+
+  if (dayOffset === undefined && !time) return undefined;
+
+  const wall = new Date(wallNow);
+  wall.setUTCDate(wallNow.getUTCDate() + (dayOffset || 0) + (time?.dayOffset || 0));
+  if (time) {
+    wall.setUTCHours(time.hours, time.minutes, 0, 0);
+  } else {
+    wall.setUTCSeconds(0, 0);
+  }
+  return toIsoString(fromWallClock(wall, timeZone));
+}
+
+const DURATION_UNIT_PATTERN = /\b(\d+(?:\.\d+)?|half an?|an?|one)[\s-]*(hours?|hrs?|minutes?|mins?)\b/g;
+
+const DAYPART_DURATION_MINUTES: Record<string, number> = {
+  morning: 180,
+  afternoon: 240,
+  evening: 180,
+  day: 480,
+};
+
+/**
+ * Extracts an explicit duration ("for 2 hours", "a 30 minute meeting",
+ * "1 hour 30 minutes", "all afternoon") as an ISO 8601 duration such as "PT1H30M".
+ * Offsets like "in 2 hours" or "30 minutes before the flight" place a time,
+ * they don't size an event, and are ignored.
+ */
+export function extractDuration(input: string): string | undefined {
+  const text = input.toLowerCase();
+
+  // "1 hour 30 minutes" / "1 hour and 30 minutes" form a single chain
+  const chains: { minutes: number; start: number; end: number }[] = [];
+  for (const match of Array.from(text.matchAll(DURATION_UNIT_PATTERN))) {
+    // "1400 hours" is a clock time, not a duration
+    if (MILITARY_PATTERN.test(match[0])) continue;
+
+    const start = match.index ?? 0;
+    const amount = match[1].startsWith("half") ? 0.5 : /^\d/.test(match[1]) ? parseFloat(match[1]) : 1;
+    const minutes = match[2].startsWith("h") ? amount * 60 : amount;
+
+    const last = chains[chains.length - 1];
+    if (last && /^\s*(and\s+)?$/.test(text.slice(last.end, start))) {
+      last.minutes += minutes;
+      last.end = start + match[0].length;
+    } else {
+      chains.push({ minutes, start, end: start + match[0].length });
+    }
+  }
+
+  let totalMinutes = chains
+    .filter(({ start, end }) =>
+      !/\bin\s+$/.test(text.slice(0, start)) &&
+      !/^\s*(before|after|early|earlier|late|later)\b/.test(text.slice(end))
+    )
+    .reduce((sum, { minutes }) => sum + minutes, 0);
+
+  if (totalMinutes === 0) {
+    const daypartMatch = text.match(/\ball (morning|afternoon|evening|day)\b/);
+    if (daypartMatch) {
+      totalMinutes = DAYPART_DURATION_MINUTES[daypartMatch[1]];
+    }
+  }
+
+  // Round before splitting so 59.6 minutes becomes "PT1H", not "PT60M"
+  const rounded = Math.round(totalMinutes);
+  if (rounded <= 0) return undefined;
+
+  const hours = Math.floor(rounded / 60);
+  const minutes = rounded % 60;
+  return `PT${hours ? `${hours}H` : ""}${minutes ? `${minutes}M` : ""}`;
+}
+
+/**
+ * Adds an ISO 8601 time duration ("PT1H30M") to a date.
+ */
+export function addIsoDuration(start: Date, duration: string): Date | undefined {
+  const match = duration.match(/^PT(?:(\d+)H)?(?:(\d+)M)?$/);
+  if (!match) return undefined;
+
+  const hours = parseInt(match[1] || "0");
+  const minutes = parseInt(match[2] || "0");
+  return new Date(start.getTime() + (hours * 60 + minutes) * 60 * 1000);
+}
+
+const RRULE_WEEKDAYS = ["SU", "MO", "TU", "WE", "TH", "FR", "SA"];
+const WEEKDAY_NAME_PATTERNS = WEEKDAYS.map((day) => new RegExp(`\\b${day}`));
+const WEEKDAY_LIST_PATTERN = new RegExp(
+  `\\b(?:every|each)\\s+((?:(?:${WEEKDAYS.join("|")})s?(?:\\s*,\\s*|\\s+and\\s+|,?\\s+&\\s+)?)+)`
+);
+
+/**
+ * Extracts a recurrence ("every day", "every weekday", "every Monday and Thursday",
+ * "weekly", "monthly") as an RFC 5545 RRULE value such as "FREQ=WEEKLY;BYDAY=MO".
+ * With explicitOnly, only "every …"/"each …" phrasing counts: in free text, words like
+ * "weekly" are usually adjectives ("the weekly report review") rather than a schedule.
+ */
+export function extractRecurrence(input: string, explicitOnly: boolean = false): string | undefined {
+  const text = input.toLowerCase();
+
+  if (/\b(every|each)\s+weekday\b/.test(text) || (!explicitOnly && /\bweekdays\b/.test(text))) {
+    return "FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR";
+  }
+
+  const weekdayMatch = text.match(WEEKDAY_LIST_PATTERN);
+  if (weekdayMatch) {
+    const days = WEEKDAY_NAME_PATTERNS
+      .map((pattern, index) => (pattern.test(weekdayMatch[1]) ? RRULE_WEEKDAYS[index] : undefined))
+      .filter((day): day is string => day !== undefined);
+    return `FREQ=WEEKLY;BYDAY=${days.join(",")}`;
+  }
+
+  if (/\b(every|each)\s*day\b/.test(text) || (!explicitOnly && /\bdaily\b/.test(text))) return "FREQ=DAILY";
+  if (/\b(every|each)\s+week\b/.test(text) || (!explicitOnly && /\bweekly\b/.test(text))) return "FREQ=WEEKLY";
+  if (/\b(every|each)\s+month\b/.test(text) || (!explicitOnly && /\bmonthly\b/.test(text))) return "FREQ=MONTHLY";
+
+  return undefined;
 }
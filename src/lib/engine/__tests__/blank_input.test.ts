@@ -0,0 +1,49 @@
+import { parseIntent, isBlankInput } from "../intent.js";
+
+async function testBlankInput() {
+  console.log("--- TEST: Blank Input Guard ---");
+
+  for (const input of ["", "   ", "?", "..."]) {
+    try {
+      await parseIntent(input);
+      console.error(`FAIL: ${JSON.stringify(input)} should be rejected before parsing`);
+      process.exit(1);
+    } catch (error: any) {
+      if (error.code === "INTENT_PARSE_FAILED" && error.message.includes("empty input")) {
+        console.log(`PASS: ${JSON.stringify(input)} rejected as empty input.`);
+      } else {
+        console.error(`FAIL: Unexpected error for ${JSON.stringify(input)}: ${error.message}`);
+        process.exit(1);
+      }
+    }
+  }
+
+  for (const input of ["book a table", "7pm?", "予約"]) {
+    if (isBlankInput(input)) {
+      console.error(`FAIL: ${JSON.stringify(input)} should not be treated as blank`);
+      process.exit(1);
+    }
+  }
+  console.log("PASS: Inputs with words or numbers pass the guard.");
+
+  for (const input of ["ª", "½", "£5", "€", "¹²³"]) {
+    if (isBlankInput(input)) {
+      console.error(`FAIL: ${JSON.stringify(input)} should not be treated as blank`);
+      process.exit(1);
+    }
+  }
+  for (const input of ["\u00A0", "\u200B\u200D", "\uFEFF ?", "\u3000。"]) {
+    if (!isBlankInput(input)) {
+      console.error(`FAIL: ${JSON.stringify(input)} should be treated as blank`);
+      process.exit(1);
+    }
+  }
+  console.log("PASS: Printable Latin-1 signs are content; invisible characters are blank.");
+
+  console.log("--- ALL TESTS PASSED ---");
+}
+
+testBlankInput().catch(err => {
+  console.error(err);
+  process.exit(1);
+});
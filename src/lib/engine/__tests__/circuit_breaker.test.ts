@@ -0,0 +1,109 @@
+import { RegistryManager } from "../registry.js";
+import { getMemoryClient } from "../memory.js";
+
+// Counters, expiries and locks kept in memory, with a clock the test advances
+function stubMemory() {
+  const clock = { now: 0 };
+  const counters = new Map<string, { count: number; expiresAt: number }>();
+  const locks = new Set<string>();
+  const memory = getMemoryClient() as any;
+
+  memory.getCounter = async (key: string) => counters.get(key)?.count ?? 0;
+  memory.getTtl = async (key: string) => {
+    const entry = counters.get(key);
+    return entry ? Math.round((entry.expiresAt - clock.now) / 1000) : -2;
+  };
+  memory.incrementCounter = async (key: string, ttlSeconds: number) => {
+    const count = (counters.get(key)?.count ?? 0) + 1;
+    counters.set(key, { count, expiresAt: clock.now + ttlSeconds * 1000 });
+    return count;
+  };
+  memory.resetCounter = async (key: string) => {
+    counters.delete(key);
+    locks.delete(key);
+  };
+  memory.acquireLock = async (key: string) => {
+    if (locks.has(key)) return false;
+    locks.add(key);
+    return true;
+  };
+
+  return clock;
+}
+
+async function testCircuitBreaker() {
+  console.log("--- TEST: MCP Circuit Breaker Transitions ---");
+
+  const clock = stubMemory();
+  const manager = new RegistryManager();
+  const server = "circuit_breaker:mcp:test";
+
+  const expectState = async (label: string, expected: string) => {
+    const state = await manager.getCircuitState(server);
+    if (state === expected) {
+      console.log(`PASS: ${label} (${state}).`);
+    } else {
+      console.error(`FAIL: ${label}: expected ${expected}, got ${state}`);
+      process.exit(1);
+    }
+  };
+
+  await expectState("Starts closed", "closed");
+
+  // closed -> open after three consecutive failures
+  for (let i = 0; i < 3; i++) {
+    await manager.recordCallOutcome(server, false, "closed");
+  }
+  await expectState("Opens after three failures", "open");
+  if (await manager.admitCall(server)) {
+    console.error("FAIL: An open circuit should refuse calls");
+    process.exit(1);
+  }
+
+  // open -> half_open once the cooldown has passed, admitting a single trial call
+  clock.now += 61 * 1000;
+  await expectState("Half-open after cooldown", "half_open");
+  const admitted = await Promise.all([manager.admitCall(server), manager.admitCall(server), manager.admitCall(server)]);
+  if (admitted.filter((admission) => admission === "trial").length === 1 && admitted.filter(Boolean).length === 1) {
+    console.log("PASS: Half-open admits exactly one concurrent trial call.");
+  } else {
+    console.error(`FAIL: Expected one trial call admitted, got ${JSON.stringify(admitted)}`);
+    process.exit(1);
+  }
+
+  // A call admitted before the circuit opened can't decide it while the trial is in flight
+  await manager.recordCallOutcome(server, true, "closed");
+  await expectState("Late success from before the circuit opened is ignored", "half_open");
+  if (await manager.admitCall(server)) {
+    console.error("FAIL: The late success should not release the trial lock");
+    process.exit(1);
+  }
+
+  // half_open -> open when the trial fails
+  await manager.recordCallOutcome(server, false, "trial");
+  await expectState("Failed trial re-opens", "open");
+
+  // half_open -> closed when the trial succeeds
+  clock.now += 61 * 1000;
+  await expectState("Half-open again after cooldown", "half_open");
+  if ((await manager.admitCall(server)) !== "trial") {
+    console.error("FAIL: A new trial call should be admitted after the failed one was recorded");
+    process.exit(1);
+  }
+  await manager.recordCallOutcome(server, true, "trial");
+  await expectState("Successful trial closes", "closed");
+
+  if (await manager.admitCall(server) && await manager.admitCall(server)) {
+    console.log("PASS: Closed circuit admits every call.");
+  } else {
+    console.error("FAIL: A closed circuit should admit all calls");
+    process.exit(1);
+  }
+
+  console.log("--- ALL TESTS PASSED ---");
+}
+
+testCircuitBreaker().catch(err => {
+  console.error(err);
+  process.exit(1);
+});
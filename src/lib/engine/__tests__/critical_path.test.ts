@@ -0,0 +1,72 @@
+import { getCriticalPathDuration } from "../planner.js";
+import { Plan, PlanStep } from "../types.js";
+import { randomUUID } from "crypto";
+
+async function testCriticalPathDuration() {
+  console.log("--- TEST: Critical Path Duration ---");
+
+  const makeStep = (stepNumber: number, timeoutMs: number, dependencies: string[] = []): PlanStep => ({
+    id: randomUUID(),
+    step_number: stepNumber,
+    tool_name: `tool${stepNumber}`,
+    parameters: {},
+    dependencies,
+    description: `Step ${stepNumber}`,
+    requires_confirmation: false,
+    timeout_ms: timeoutMs,
+  });
+
+  // Two independent steps (1s and 3s) followed by one step depending on both (0.5s)
+  const fetchA = makeStep(0, 1000);
+  const fetchB = makeStep(1, 3000);
+  const combine = makeStep(2, 500, [fetchA.id, fetchB.id]);
+
+  const plan: Plan = {
+    id: randomUUID(),
+    intent_id: randomUUID(),
+    steps: [fetchA, fetchB, combine],
+    constraints: {
+      max_steps: 10,
+      max_total_tokens: 1000,
+      max_execution_time_ms: 10000,
+    },
+    metadata: {
+      version: "1.0.0",
+      created_at: new Date().toISOString(),
+      planning_model_id: "test",
+      estimated_total_tokens: 0,
+      estimated_latency_ms: 0,
+    },
+    summary: "Critical path test plan",
+  };
+
+  // Test 1: Parallel steps overlap, dependent step adds to the longest branch
+  console.log("Test 1: Parallel branches overlap...");
+  const total = getCriticalPathDuration(plan);
+
+  if (total === 3500) {
+    console.log("PASS: Critical path is 3500ms (3000ms branch + 500ms dependent).");
+  } else {
+    console.error(`FAIL: Expected 3500ms, got ${total}ms`);
+    process.exit(1);
+  }
+
+  // Test 2: Custom duration estimates override step timeouts
+  console.log("Test 2: Custom duration estimates...");
+  const estimates = new Map([[fetchA.id, 4000], [fetchB.id, 2000], [combine.id, 1000]]);
+  const estimated = getCriticalPathDuration(plan, (step) => estimates.get(step.id)!);
+
+  if (estimated === 5000) {
+    console.log("PASS: Critical path follows the slower branch under custom estimates.");
+  } else {
+    console.error(`FAIL: Expected 5000ms, got ${estimated}ms`);
+    process.exit(1);
+  }
+
+  console.log("--- ALL TESTS PASSED ---");
+}
+
+testCriticalPathDuration().catch(err => {
+  console.error(err);
+  process.exit(1);
+});
@@ -0,0 +1,61 @@
+import { createServer } from "http";
+import { AddressInfo } from "net";
+import { RegistryManager } from "../registry.js";
+
+async function testHealthProbe() {
+  console.log("--- TEST: MCP Server Health Probes ---");
+
+  // Mock server: /healthy answers immediately, /hanging never responds
+  const server = createServer((req, res) => {
+    if (req.url === "/healthy") {
+      res.writeHead(200, { "Content-Type": "text/plain" });
+      res.end("ok");
+    }
+  });
+  await new Promise<void>((resolve) => server.listen(0, "127.0.0.1", resolve));
+  const { port } = server.address() as AddressInfo;
+
+  const manager = new RegistryManager();
+  manager.registerServer("mock-healthy", `http://127.0.0.1:${port}/healthy`);
+  manager.registerServer("mock-hanging", `http://127.0.0.1:${port}/hanging`);
+
+  try {
+    const results = await manager.refreshHealth(500);
+    const healthy = results.find((r) => r.server === "mock-healthy");
+    const hanging = results.find((r) => r.server === "mock-hanging");
+
+    console.log(`Probe results: ${JSON.stringify([healthy, hanging])}`);
+
+    if (healthy?.healthy === true) {
+      console.log("PASS: Responsive server reported healthy.");
+    } else {
+      console.error("FAIL: Expected mock-healthy to be healthy");
+      process.exit(1);
+    }
+
+    if (hanging?.healthy === false && hanging.error?.includes("Timed out")) {
+      console.log("PASS: Unresponsive server reported unhealthy after timeout.");
+    } else {
+      console.error("FAIL: Expected mock-hanging to time out");
+      process.exit(1);
+    }
+
+    const cached = manager.getCachedHealth();
+    if (cached["mock-healthy"] === true && cached["mock-hanging"] === false) {
+      console.log("PASS: Cached availability updated from probe.");
+    } else {
+      console.error(`FAIL: Unexpected cached health ${JSON.stringify(cached)}`);
+      process.exit(1);
+    }
+  } finally {
+    server.closeAllConnections();
+    server.close();
+  }
+
+  console.log("--- ALL TESTS PASSED ---");
+}
+
+testHealthProbe().catch(err => {
+  console.error(err);
+  process.exit(1);
+});
@@ -0,0 +1,75 @@
+import { parseIntentBatch, ParseResult, ParseContext } from "../intent.js";
+
+async function testIntentBatch() {
+  console.log("--- TEST: Batch Intent Parsing ---");
+
+  const inputs = [
+    "Book a table for 4 at Nopa tonight",
+    "What's the weather in Tokyo?",
+    "Get me an Uber to SFO",
+    "Schedule a 1:1 every Monday at 10am",
+    "Find ramen near me",
+    "Remind me to call mom",
+    "Text Sam I'm running late",
+  ];
+
+  // Stub parser with uneven latency, so later inputs often finish before earlier ones
+  let inFlight = 0;
+  let maxInFlight = 0;
+  const contexts: ParseContext[] = [];
+  const stubParse = async (input: string, context: ParseContext): Promise<ParseResult> => {
+    inFlight++;
+    maxInFlight = Math.max(maxInFlight, inFlight);
+    contexts.push(context);
+    await new Promise((resolve) => setTimeout(resolve, 5 + ((input.length * 7) % 25)));
+    inFlight--;
+    return { intent: { rawText: input } } as ParseResult;
+  };
+
+  const context: ParseContext = { execution_id: "batch-test" };
+  const results = await parseIntentBatch(inputs, context, 3, stubParse);
+
+  console.log(`Parsed ${results.length} inputs, at most ${maxInFlight} in flight`);
+
+  if (results.length !== inputs.length) {
+    console.error(`FAIL: Expected ${inputs.length} results, got ${results.length}`);
+    process.exit(1);
+  }
+
+  const mismatched = results.filter((r, i) => r.intent.rawText !== inputs[i]);
+  if (mismatched.length === 0) {
+    console.log("PASS: Results returned in input order.");
+  } else {
+    console.error(`FAIL: Results out of order: ${mismatched.map((r) => r.intent.rawText).join(", ")}`);
+    process.exit(1);
+  }
+
+  if (maxInFlight === 3) {
+    console.log("PASS: No more than 3 parses in flight at once.");
+  } else {
+    console.error(`FAIL: Expected at most 3 concurrent parses (and the pool filled), saw ${maxInFlight}`);
+    process.exit(1);
+  }
+
+  if (contexts.every((c) => c === context)) {
+    console.log("PASS: Context shared with every parse.");
+  } else {
+    console.error("FAIL: Every parse should receive the batch context");
+    process.exit(1);
+  }
+
+  const empty = await parseIntentBatch([], {}, 4, stubParse);
+  if (empty.length === 0) {
+    console.log("PASS: Empty batch returns no results.");
+  } else {
+    console.error("FAIL: Empty batch should return no results");
+    process.exit(1);
+  }
+
+  console.log("--- ALL TESTS PASSED ---");
+}
+
+testIntentBatch().catch(err => {
+  console.error(err);
+  process.exit(1);
+});
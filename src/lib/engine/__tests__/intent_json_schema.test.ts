@@ -0,0 +1,43 @@
+import { getIntentJsonSchema } from "../schema-utils.js";
+
+async function testIntentJsonSchema() {
+  console.log("--- TEST: Intent JSON Schema Export ---");
+
+  const schema = getIntentJsonSchema() as {
+    type: string;
+    properties: Record<string, Record<string, unknown>>;
+    required: string[];
+  };
+
+  console.log(`Properties: ${Object.keys(schema.properties).join(", ")}`);
+
+  const intentType = schema.properties.type;
+  if (Array.isArray(intentType?.enum) && intentType.enum.includes("SCHEDULE") && intentType.enum.includes("CLARIFICATION_REQUIRED")) {
+    console.log("PASS: Intent type enum exported.");
+  } else {
+    console.error(`FAIL: Unexpected type schema ${JSON.stringify(intentType)}`);
+    process.exit(1);
+  }
+
+  const confidence = schema.properties.confidence;
+  if (confidence?.type === "number" && confidence.minimum === 0 && confidence.maximum === 1) {
+    console.log("PASS: Confidence bounds exported.");
+  } else {
+    console.error(`FAIL: Unexpected confidence schema ${JSON.stringify(confidence)}`);
+    process.exit(1);
+  }
+
+  if (schema.required.includes("rawText") && !schema.required.includes("requires_clarification")) {
+    console.log("PASS: Defaulted fields are optional for clients.");
+  } else {
+    console.error(`FAIL: Unexpected required fields ${JSON.stringify(schema.required)}`);
+    process.exit(1);
+  }
+
+  console.log("--- ALL TESTS PASSED ---");
+}
+
+testIntentJsonSchema().catch(err => {
+  console.error(err);
+  process.exit(1);
+});
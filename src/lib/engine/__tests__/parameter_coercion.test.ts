@@ -0,0 +1,111 @@
+import { ToolRegistry } from "../tools/registry.js";
+import { wordsToNumber } from "../schema-utils.js";
+import { ToolDefinition } from "../types.js";
+
+async function testParameterCoercion() {
+  console.log("--- TEST: Tool Parameter Coercion ---");
+
+  const registry = new ToolRegistry();
+  const bookingTool: ToolDefinition = {
+    name: "book_table",
+    version: "1.0.0",
+    description: "Book a restaurant table",
+    inputSchema: {
+      type: "object",
+      properties: {
+        restaurant: { type: "string", description: "Restaurant name" },
+        party_size: { type: "integer", description: "Number of guests" },
+        outdoor: { type: "boolean", description: "Outdoor seating" },
+      },
+      required: ["restaurant", "party_size"],
+    },
+    return_schema: {},
+    category: "action",
+    timeout_ms: 30000,
+    requires_confirmation: false,
+  };
+
+  let received: Record<string, unknown> | undefined;
+  registry.register(bookingTool, async (params) => {
+    received = params;
+    return { success: true, output: { booked: true } };
+  });
+
+  const context = {
+    executionId: "test-exec",
+    stepId: "test-step",
+    timeoutMs: 30000,
+    startTime: Date.now(),
+  };
+
+  // Test 1: String-encoded scalars are coerced to declared types
+  console.log("Test 1: Coercion of string-encoded scalars...");
+  const result1 = await registry.execute(
+    "book_table",
+    { restaurant: "Nopa", party_size: "4", outdoor: "true" },
+    context
+  );
+
+  if (result1.success && received?.party_size === 4 && received?.outdoor === true) {
+    console.log("PASS: party_size and outdoor coerced to number and boolean.");
+  } else {
+    console.error(`FAIL: Expected coerced parameters, got ${JSON.stringify(received)} (${result1.error})`);
+    process.exit(1);
+  }
+
+  // Test 2: Genuinely mistyped values are rejected with a descriptive error
+  console.log("Test 2: Rejection of mistyped values...");
+  received = undefined;
+  const result2 = await registry.execute(
+    "book_table",
+    { restaurant: "Nopa", party_size: "abc" },
+    context
+  );
+
+  if (!result2.success && received === undefined && result2.error?.includes("party_size")) {
+    console.log("PASS: Mistyped party_size rejected before execution.");
+  } else {
+    console.error(`FAIL: Expected validation error naming party_size, got ${result2.error}`);
+    process.exit(1);
+  }
+
+  // Test 3: Word-form numbers are coerced for numeric parameters
+  console.log("Test 3: Word-form numbers...");
+  const wordCases: [string, number | undefined][] = [
+    ["four", 4],
+    ["a couple", 2],
+    ["a dozen", 12],
+    ["twenty one", 21],
+    ["twenty-one", 21],
+    ["an", 1],
+    ["four five", undefined],
+    ["abc", undefined],
+  ];
+  for (const [input, expected] of wordCases) {
+    const actual = wordsToNumber(input);
+    if (actual !== expected) {
+      console.error(`FAIL: wordsToNumber("${input}") expected ${expected}, got ${actual}`);
+      process.exit(1);
+    }
+  }
+
+  const result3 = await registry.execute(
+    "book_table",
+    { restaurant: "Nopa", party_size: "four" },
+    context
+  );
+
+  if (result3.success && received?.party_size === 4) {
+    console.log("PASS: Word-form party_size coerced to 4.");
+  } else {
+    console.error(`FAIL: Expected party_size 4, got ${JSON.stringify(received)} (${result3.error})`);
+    process.exit(1);
+  }
+
+  console.log("--- ALL TESTS PASSED ---");
+}
+
+testParameterCoercion().catch(err => {
+  console.error(err);
+  process.exit(1);
+});
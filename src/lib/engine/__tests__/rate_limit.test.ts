@@ -0,0 +1,108 @@
+import { ToolRegistry } from "../tools/registry.js";
+import { ToolDefinition } from "../types.js";
+
+async function testRateLimits() {
+  console.log("--- TEST: Per-Tool Rate Limits ---");
+
+  const registry = new ToolRegistry();
+  const searchTool: ToolDefinition = {
+    name: "search_restaurant",
+    version: "1.0.0",
+    description: "Search for restaurants",
+    inputSchema: {
+      type: "object",
+      properties: {
+        cuisine: { type: "string", description: "Cuisine type" },
+      },
+      required: [],
+    },
+    return_schema: {},
+    category: "search",
+    timeout_ms: 30000,
+    requires_confirmation: false,
+    rate_limits: { requests_per_minute: 3 },
+  };
+
+  let calls = 0;
+  registry.register(searchTool, async () => {
+    calls++;
+    return { success: true, output: { results: [] } };
+  });
+
+  const context = {
+    executionId: "test-exec",
+    stepId: "test-step",
+    timeoutMs: 30000,
+    startTime: Date.now(),
+  };
+
+  // Test 1: Calls beyond the limit are rejected with a retry-after hint
+  console.log("Test 1: Firing more calls than the per-minute limit...");
+  const results = [];
+  for (let i = 0; i < 5; i++) {
+    results.push(await registry.execute("search_restaurant", { cuisine: "italian" }, context));
+  }
+
+  const allowed = results.filter((r) => r.success).length;
+  const rejected = results.filter((r) => !r.success);
+
+  if (allowed === 3 && calls === 3 && rejected.length === 2 && rejected.every((r) => r.error?.includes("retry after"))) {
+    console.log(`PASS: 3 calls allowed, 2 rejected (${rejected[0].error}).`);
+  } else {
+    console.error(`FAIL: Expected 3 allowed and 2 rate limited, got ${JSON.stringify(results)}`);
+    process.exit(1);
+  }
+
+  // Test 2: The window slides, so capacity returns once old calls age out
+  console.log("Test 2: Sliding window recovery...");
+  const later = registry.tryAcquire("search_restaurant", Date.now() + 61 * 1000);
+  if (later.allowed) {
+    console.log("PASS: Calls allowed again after the window elapsed.");
+  } else {
+    console.error(`FAIL: Expected capacity after a minute, got ${JSON.stringify(later)}`);
+    process.exit(1);
+  }
+
+  // Test 3: Tools without rate limits are never throttled
+  console.log("Test 3: Unlimited tools...");
+  registry.register({ ...searchTool, name: "unlimited_search", rate_limits: undefined }, async () => ({ success: true, output: {} }));
+  const unlimited = Array.from({ length: 10 }, () => registry.tryAcquire("unlimited_search"));
+  if (unlimited.every((r) => r.allowed)) {
+    console.log("PASS: Tools without rate limits are not throttled.");
+  } else {
+    console.error("FAIL: Unlimited tool was throttled");
+    process.exit(1);
+  }
+
+  // Test 4: Calls rejected by parameter validation don't use up the quota
+  console.log("Test 4: Invalid calls are not counted...");
+  registry.register({
+    ...searchTool,
+    name: "strict_search",
+    inputSchema: { ...searchTool.inputSchema, required: ["cuisine"] },
+    rate_limits: { requests_per_minute: 2 },
+  }, async () => ({ success: true, output: { results: [] } }));
+
+  const invalid = [];
+  for (let i = 0; i < 3; i++) {
+    invalid.push(await registry.execute("strict_search", {}, context));
+  }
+  const valid = [];
+  for (let i = 0; i < 2; i++) {
+    valid.push(await registry.execute("strict_search", { cuisine: "thai" }, context));
+  }
+
+  if (invalid.every((r) => r.error?.includes("validation")) && valid.every((r) => r.success)) {
+    console.log("PASS: Both valid calls allowed after three invalid ones.");
+  } else {
+    console.error(`FAIL: Invalid calls consumed the quota: ${JSON.stringify({ invalid, valid })}`);
+    process.exit(1);
+  }
+
+  console.log("--- ALL TESTS PASSED ---");
+}
+
+testRateLimits().catch(err => {
+  console.error(err);
+  process.exit(1);
+});
@@ -23,6 +23,15 @@ import {
   EngineErrorSchema,
 } from "./types";
 import { generateStructured, GenerateStructuredResult } from "./llm";
+import { isBlankInput } from "../normalization";
+
+// ============================================================================
+// INPUT GUARD
+// Reject input that carries no intent before classification
+// ============================================================================
+
+// Shared with the edge intent route so both reject the same inputs
+export { isBlankInput };
 
 // ============================================================================
 // INTENT HASHING
@@ -199,11 +208,11 @@ export async function parseIntent(
   const timestamp = new Date().toISOString();
 
   try {
-    // Validate input
-    if (!input || typeof input !== "string" || input.trim().length === 0) {
+    // Validate input before spending a model call on it
+    if (!input || typeof input !== "string" || isBlankInput(input)) {
       throw EngineErrorSchema.parse({
         code: "INTENT_PARSE_FAILED",
-        message: "Invalid input: empty or non-string input provided",
+        message: "Invalid input: empty input provided (no words or numbers to parse)",
         details: { input_type: typeof input },
         recoverable: false,
         timestamp,
@@ -400,20 +409,29 @@ export function validateIntentConfidence(
 }
 
 // ============================================================================
-// BATCH PARSE (for testing/validation)
-// Parse multiple inputs in sequence
+// BATCH PARSE
+// Parse multiple inputs concurrently, preserving input order
 // ============================================================================
 
 export async function parseIntentBatch(
   inputs: string[],
-  context: ParseContext = {}
+  context: ParseContext = {},
+  concurrency: number = 4,
+  parse: (input: string, context: ParseContext) => Promise<ParseResult> = parseIntent
 ): Promise<ParseResult[]> {
-  const results: ParseResult[] = [];
+  const results: ParseResult[] = new Array(inputs.length);
+  let nextIndex = 0;
+
+  // A bounded pool of workers pulls inputs in order and writes each result back to its input's slot
+  const worker = async () => {
+    while (nextIndex < inputs.length) {
+      const index = nextIndex++;
+      results[index] = await parse(inputs[index], context);
+    }
+  };
 
-  for (const input of inputs) {
-    const result = await parseIntent(input, context);
-    results.push(result);
-  }
+  const workerCount = Math.min(Math.max(1, concurrency), inputs.length);
+  await Promise.all(Array.from({ length: workerCount }, worker));
 
   return results;
 }
@@ -331,9 +331,8 @@ export class MemoryClient {
   async incrementCounter(key: string, ttlSeconds: number): Promise<number> {
     try {
       const count = await this.redis.incr(key);
-      if (count === 1) {
-        await this.redis.expire(key, ttlSeconds);
-      }
+      // Refresh the expiry on every increment so the TTL tracks the most recent event
+      await this.redis.expire(key, ttlSeconds);
       return count;
     } catch (error) {
       console.error(`Failed to increment counter for ${key}:`, error);
@@ -351,6 +350,26 @@ export class MemoryClient {
     }
   }
 
+  async resetCounter(key: string): Promise<void> {
+    try {
+      await this.redis.del(key);
+    } catch (error) {
+      console.error(`Failed to reset counter for ${key}:`, error);
+    }
+  }
+
+  /**
+   * Sets a key only if it is absent, expiring after ttlSeconds. Returns true for the single caller that set it.
+   */
+  async acquireLock(key: string, ttlSeconds: number): Promise<boolean> {
+    try {
+      return (await this.redis.set(key, "1", { nx: true, ex: ttlSeconds })) === "OK";
+    } catch (error) {
+      console.error(`Failed to acquire lock for ${key}:`, error);
+      return false;
+    }
+  }
+
   async getRecentSuccessfulIntents(limit: number = 3): Promise<ExecutionState[]> {
     try {
       // This is a simplified query. In a real system, we'd use a separate index or list for successful intents.
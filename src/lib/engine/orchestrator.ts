@@ -12,6 +12,7 @@ import {
   TraceEntry,
   EngineErrorSchema,
   EngineErrorCode,
+  isTerminalStatus,
 } from "./types";
 import {
   ExecutionStateMachine,
@@ -26,6 +27,7 @@ import {
 import { saveExecutionState, getMemoryClient } from "./memory";
 import { MCPClient } from "../../infrastructure/mcp/MCPClient";
 import { validateOutputAgainstConstraints } from "./intent";
+import { signApprovalToken, verifyApprovalToken } from "../auth";
 
 // ============================================================================
 // SCORE OUTCOME
@@ -62,6 +64,7 @@ export interface ExecutionResult {
   total_steps: number;
   execution_time_ms: number;
   summary?: string;
+  approval_token?: string; // Issued when paused for confirmation; required to resume
   usage?: {
     prompt_tokens: number;
     completion_tokens: number;
@@ -85,12 +88,16 @@ export interface ToolExecutor {
   execute(
     toolName: string,
     parameters: Record<string, unknown>,
-    timeoutMs: number
+    timeoutMs: number,
+    // Aborted when the plan's time budget runs out while the call is in flight
+    signal?: AbortSignal
   ): Promise<{
     success: boolean;
     output?: unknown;
     error?: string;
     latency_ms: number;
+    // Set when the tool was rate limited: how long until a retry can succeed
+    retry_after_ms?: number;
   }>;
 }
 
@@ -104,6 +111,9 @@ interface StepExecutionContext {
   step: PlanStep;
   toolExecutor: ToolExecutor;
   traceCallback?: (entry: TraceEntry) => void;
+  dryRun?: boolean;
+  confirmed?: boolean;
+  signal?: AbortSignal;
 }
 
 // ============================================================================
@@ -195,6 +205,28 @@ function resolveStepParameters(
   return resolved;
 }
 
+// ============================================================================
+// TRACE STATE CHANGE
+// Emit a lifecycle event whenever the execution status changes
+// ============================================================================
+
+function traceStateChange(
+  previous: ExecutionState,
+  next: ExecutionState,
+  traceCallback?: (entry: TraceEntry) => void
+): ExecutionState {
+  if (traceCallback && previous.status !== next.status) {
+    traceCallback({
+      timestamp: new Date().toISOString(),
+      phase: "system",
+      event: "state_changed",
+      input: { from: previous.status },
+      output: { to: next.status },
+    });
+  }
+  return next;
+}
+
 // ============================================================================
 // EXECUTE SINGLE STEP
 // Execute one step with timeout and error handling
@@ -203,7 +235,7 @@ function resolveStepParameters(
 async function executeStep(
   context: StepExecutionContext
 ): Promise<StepExecutionState> {
-  const { state, step, toolExecutor, traceCallback } = context;
+  const { state, step, toolExecutor, traceCallback, dryRun, confirmed, signal } = context;
   const stepStartTime = performance.now();
   const timestamp = new Date().toISOString();
 
@@ -222,6 +254,15 @@ async function executeStep(
         attempts: (getStepState(state, step.id)?.attempts || 0) + 1,
       });
 
+      if (traceCallback) {
+        traceCallback({
+          timestamp,
+          phase: "execution",
+          step_id: step.id,
+          event: "step_started",
+        });
+      }
+
       const resolvedParameters = resolveStepParameters(step, stepState);
 
       // Task 2: Fix Input Mapping - Dynamic Parameter Bridge
@@ -244,7 +285,9 @@ async function executeStep(
                            step.tool_name.toLowerCase().includes("reserve") || 
                            step.tool_name.toLowerCase().includes("schedule");
       
-      if (isActionStep && state.intent?.parameters) {
+      // Steps the user just approved were already reviewed with these parameters, so they don't pause again.
+      // A dry run performs nothing to guard, and must not call the model to validate constraints.
+      if (!dryRun && !confirmed && isActionStep && state.intent?.parameters) {
         // Extract qualitative constraints from intent (e.g., "romantic", "cheap")
         const qualitativeConstraints: string[] = [];
         const params = state.intent.parameters;
@@ -256,6 +299,16 @@ async function executeStep(
         if (qualitativeConstraints.length > 0) {
           const validation = await validateOutputAgainstConstraints(resolvedParameters, qualitativeConstraints);
           if (!validation.valid) {
+            if (traceCallback) {
+              traceCallback({
+                timestamp,
+                phase: "execution",
+                step_id: step.id,
+                event: "guardrail_violation",
+                input: resolvedParameters,
+                error: validation.reason,
+              });
+            }
             return {
               step_id: step.id,
               status: "awaiting_confirmation",
@@ -274,8 +327,8 @@ async function executeStep(
         input: resolvedParameters,
       });
 
-      // Task 1: Enforce Confirmation Guardrails
-      if (step.requires_confirmation || toolDef?.requires_confirmation) {
+      // Task 1: Enforce Confirmation Guardrails (a dry run never performs the action, so it doesn't pause)
+      if (!dryRun && !confirmed && (step.requires_confirmation || toolDef?.requires_confirmation)) {
         // If we're here, we need to pause and wait for confirmation
         // In a real system, this would involve updating the state to AWAITING_CONFIRMATION
         // and returning so the caller can handle the UI interaction.
@@ -290,7 +343,8 @@ async function executeStep(
       const toolResult = await toolExecutor.execute(
         step.tool_name,
         resolvedParameters,
-        step.timeout_ms
+        step.timeout_ms,
+        signal
       );
 
       const stepEndTime = performance.now();
@@ -350,6 +404,7 @@ async function executeStep(
           error: {
             code: isValidationError ? "TOOL_VALIDATION_FAILED" : "TOOL_EXECUTION_FAILED",
             message: toolResult.error || "Unknown tool execution error",
+            details: toolResult.retry_after_ms !== undefined ? { retry_after_ms: toolResult.retry_after_ms } : undefined,
           },
           completed_at: new Date().toISOString(),
           latency_ms: latencyMs,
@@ -389,6 +444,93 @@ async function executeStep(
   });
 }
 
+// ============================================================================
+// COMPENSATE COMPLETED STEPS
+// Undo completed steps in reverse completion order after an unrecoverable failure
+// ============================================================================
+
+async function compensateCompletedSteps(
+  plan: Plan,
+  state: ExecutionState,
+  toolExecutor: ToolExecutor,
+  traceCallback?: (entry: TraceEntry) => void
+): Promise<ExecutionState> {
+  const toCompensate = getCompletedSteps(state)
+    .map((stepState) => ({ stepState, step: plan.steps.find((s) => s.id === stepState.step_id) }))
+    .filter((c): c is { stepState: StepExecutionState; step: PlanStep } => !!c.step?.compensation)
+    .sort((a, b) =>
+      (b.stepState.completed_at || "").localeCompare(a.stepState.completed_at || "") ||
+      b.step.step_number - a.step.step_number
+    );
+
+  for (const { step } of toCompensate) {
+    state = await compensateStep(step, state, toolExecutor, traceCallback);
+  }
+
+  return state;
+}
+
+async function compensateStep(
+  step: PlanStep,
+  state: ExecutionState,
+  toolExecutor: ToolExecutor,
+  traceCallback?: (entry: TraceEntry) => void
+): Promise<ExecutionState> {
+  const compensation = step.compensation!;
+  const timestamp = new Date().toISOString();
+
+  // Compensation parameters may reference the step's own output, e.g. "$<step_id>.booking_id"
+  const parameters = resolveStepParameters({ ...step, parameters: compensation.parameters }, state);
+
+  try {
+    const result = await toolExecutor.execute(compensation.tool_name, parameters, step.timeout_ms);
+
+    if (traceCallback) {
+      traceCallback({
+        timestamp,
+        phase: "execution",
+        step_id: step.id,
+        event: result.success ? "step_compensated" : "compensation_failed",
+        input: parameters,
+        output: result.success ? result.output : undefined,
+        error: result.success ? undefined : result.error,
+        latency_ms: result.latency_ms,
+      });
+    }
+
+    if (result.success) {
+      return updateStepState(state, step.id, { status: "compensated" });
+    }
+    console.error(`[Compensation] ${compensation.tool_name} failed for step ${step.id}: ${result.error}`);
+  } catch (error) {
+    console.error(`[Compensation] ${compensation.tool_name} threw for step ${step.id}:`, error);
+  }
+
+  return state;
+}
+
+/**
+ * Undoes a step abandoned at the deadline if its tool ignores the abort and succeeds anyway.
+ * Runs in the background, since the execution has already ended in TIMEOUT.
+ */
+function compensateLateCompletion(
+  step: PlanStep,
+  run: Promise<StepExecutionState>,
+  state: ExecutionState,
+  toolExecutor: ToolExecutor,
+  traceCallback?: (entry: TraceEntry) => void
+): void {
+  if (!step.compensation) return;
+
+  run
+    .then((result) => {
+      if (result.status === "completed") {
+        return compensateStep(step, updateStepState(state, step.id, result), toolExecutor, traceCallback);
+      }
+    })
+    .catch((error) => console.error(`[Compensation] Late completion of step ${step.id} could not be undone:`, error));
+}
+
 // ============================================================================
 // SUMMARIZE RESULTS
 // Generate a concise summary of all tool execution results
@@ -432,6 +574,34 @@ async function summarizeResults(
   }
 }
 
+// ============================================================================
+// EXECUTION DEADLINE
+// Bounds the execute phase by the plan's max_execution_time_ms
+// ============================================================================
+
+type DeadlineResult<T> = { timed_out: false; value: T } | { timed_out: true };
+
+async function beforeDeadline<T>(promise: Promise<T>, deadline: number): Promise<DeadlineResult<T>> {
+  const remainingMs = deadline - performance.now();
+  if (remainingMs <= 0) {
+    return { timed_out: true };
+  }
+
+  let timeoutId: ReturnType<typeof setTimeout> | undefined;
+  const timeout = new Promise<DeadlineResult<T>>((resolve) => {
+    timeoutId = setTimeout(() => resolve({ timed_out: true }), remainingMs);
+  });
+
+  try {
+    return await Promise.race([
+      promise.then((value): DeadlineResult<T> => ({ timed_out: false, value })),
+      timeout,
+    ]);
+  } finally {
+    clearTimeout(timeoutId);
+  }
+}
+
 // ============================================================================
 // EXECUTE PLAN
 // Main execution entry point with parallel execution and reflection
@@ -445,16 +615,27 @@ export async function executePlan(
     initialState?: ExecutionState;
     traceCallback?: (entry: TraceEntry) => void;
     persistState?: boolean;
+    dryRun?: boolean;
+    // Steps the user approved; only these skip the confirmation pause and guardrail check
+    confirmedStepIds?: string[];
   } = {}
 ): Promise<ExecutionResult> {
   const startTime = performance.now();
+  const deadline = startTime + plan.constraints.max_execution_time_ms;
   const executionId = options.executionId || crypto.randomUUID();
 
+  // Dry runs resolve and validate every step against the registry but stub the tool calls,
+  // and leave no persisted state or outcome score behind
+  if (options.dryRun) {
+    toolExecutor = getToolRegistry().createToolExecutor({ dryRun: true });
+    options = { ...options, persistState: false };
+  }
+
   let state = options.initialState || createInitialState(executionId);
-  state = applyStateUpdate(state, { plan, status: "PLANNED" });
+  state = traceStateChange(state, applyStateUpdate(state, { plan, status: "PLANNED" }), options.traceCallback);
 
   try {
-    state = transitionState(state, "EXECUTING");
+    state = traceStateChange(state, transitionState(state, "EXECUTING"), options.traceCallback);
   } catch (error) {
     throw EngineErrorSchema.parse({
       code: "STATE_TRANSITION_INVALID",
@@ -478,6 +659,13 @@ export async function executePlan(
     await saveExecutionState(state);
   }
 
+  const executionTimeoutError = () => EngineErrorSchema.parse({
+    code: "EXECUTION_TIMEOUT",
+    message: `Execution exceeded its time budget of ${plan.constraints.max_execution_time_ms}ms`,
+    recoverable: false,
+    timestamp: new Date().toISOString(),
+  });
+
   try {
     while (true) {
       const readySteps = findReadySteps(plan, state);
@@ -506,22 +694,61 @@ export async function executePlan(
         }
       }
 
-      // Execute ready steps in parallel
-      const stepResultsSettled = await Promise.allSettled(
-        readySteps.map((step) =>
-          executeStep({
-            state,
-            step,
-            toolExecutor,
-            traceCallback: options.traceCallback,
-          })
-        )
+      // Nothing new starts once the time budget is spent
+      if (performance.now() >= deadline) {
+        throw executionTimeoutError();
+      }
+
+      // Execute ready steps in parallel, each raced against what is left of the plan's time budget
+      const abortController = new AbortController();
+      const stepRuns = readySteps.map((step) =>
+        executeStep({
+          state,
+          step,
+          toolExecutor,
+          traceCallback: options.traceCallback,
+          dryRun: options.dryRun,
+          confirmed: options.confirmedStepIds?.includes(step.id),
+          signal: abortController.signal,
+        })
       );
+      const rounds = await Promise.all(
+        stepRuns.map((run) => beforeDeadline(Promise.allSettled([run]).then(([settled]) => settled), deadline))
+      );
+
+      if (rounds.some((round) => round.timed_out)) {
+        // Steps that finished in time keep their results, so completed ones are compensated below;
+        // the rest are aborted and don't hold the execution past its budget
+        abortController.abort();
+        readySteps.forEach((step, i) => {
+          const round = rounds[i];
+          if (!round.timed_out) {
+            if (round.value.status === "fulfilled") {
+              state = updateStepState(state, step.id, round.value.value);
+            }
+            return;
+          }
+
+          state = updateStepState(state, step.id, {
+            status: "failed",
+            error: {
+              code: "STEP_TIMEOUT",
+              message: "Step did not complete within the execution time budget",
+            },
+            completed_at: new Date().toISOString(),
+          });
+          compensateLateCompletion(step, stepRuns[i], state, toolExecutor, options.traceCallback);
+        });
+        throw executionTimeoutError();
+      }
+
+      const stepResultsSettled = rounds.flatMap((round) => (round.timed_out ? [] : [round.value]));
 
       let anyFailed = false;
       let anyAwaitingConfirmation = false;
       let failedStepResult: StepExecutionState | undefined;
       let failedStep: PlanStep | undefined;
+      let retryBackoffMs = 0;
 
       for (let i = 0; i < stepResultsSettled.length; i++) {
         const settledResult = stepResultsSettled[i];
@@ -531,8 +758,26 @@ export async function executePlan(
           const result = settledResult.value;
           state = updateStepState(state, step.id, result);
           if (result.status === "failed") {
+            // Retry execution failures according to the step's retry policy, with exponential backoff
+            const maxAttempts = step.retry_policy?.max_attempts || 1;
+            if (result.error?.code !== "TOOL_VALIDATION_FAILED" && (result.attempts || 0) < maxAttempts) {
+              // A rate-limited tool's retry-after outlasts a shorter backoff, so the retry isn't refused again
+              const retryAfterMs = (result.error?.details as { retry_after_ms?: number } | undefined)?.retry_after_ms ?? 0;
+              const backoffMs = Math.max(
+                (step.retry_policy?.backoff_ms ?? 1000) * Math.pow(2, (result.attempts || 1) - 1),
+                retryAfterMs
+              );
+              console.log(`[Retry] Step ${step.tool_name} failed (attempt ${result.attempts}/${maxAttempts}), retrying in ${backoffMs}ms`);
+              retryBackoffMs = Math.max(retryBackoffMs, backoffMs);
+              state = updateStepState(state, step.id, {
+                status: "pending",
+                attempts: result.attempts,
+              });
+              continue;
+            }
+
             // Task 4: Automatic Plan Repair - Mini-Plan-Refinement for validation errors
-            if (result.error?.code === "TOOL_VALIDATION_FAILED" && (result.attempts || 0) < 3) {
+            if (!options.dryRun && result.error?.code === "TOOL_VALIDATION_FAILED" && (result.attempts || 0) < 3) {
               console.log(`[Plan Repair] Attempting mini-refinement for step ${step.tool_name} (attempt ${result.attempts})`);
               
               const repairPrompt = `The execution of step "${step.description}" failed due to a parameter validation error:
@@ -597,7 +842,7 @@ Respond with ONLY a JSON object containing the corrected parameters.`;
       }
 
       if (anyAwaitingConfirmation && !anyFailed) {
-        state = applyStateUpdate(state, { status: "AWAITING_CONFIRMATION" });
+        state = traceStateChange(state, applyStateUpdate(state, { status: "AWAITING_CONFIRMATION" }), options.traceCallback);
         if (options.persistState !== false) {
           await saveExecutionState(state);
         }
@@ -609,12 +854,20 @@ Respond with ONLY a JSON object containing the corrected parameters.`;
           failed_steps: 0,
           total_steps: plan.steps.length,
           execution_time_ms: Math.round(endTime - startTime),
+          // Binds the user's approval to this exact plan, execution and paused steps,
+          // so it can't be replayed against another or stretched over later steps
+          approval_token: options.dryRun ? undefined : await signApprovalToken(plan, undefined, {
+            execution_id: state.execution_id,
+            step_ids: state.step_states
+              .filter((s) => s.status === "awaiting_confirmation")
+              .map((s) => s.step_id),
+          }),
         };
       }
 
       if (anyFailed && failedStepResult && failedStep) {
         // Reflection Logic
-        state = applyStateUpdate(state, { status: "REFLECTING" });
+        state = traceStateChange(state, applyStateUpdate(state, { status: "REFLECTING" }), options.traceCallback);
         if (options.persistState !== false) {
           await saveExecutionState(state);
         }
@@ -636,17 +889,20 @@ Error: ${failedStepResult.error?.message}
 
 Respond with only the updated steps for the remaining plan, ensuring dependencies are correct.`;
 
-          const reflectionResponse = await generateText({
-            modelType: "planning",
-            prompt,
-            systemPrompt: "You are a resilient execution engine. Your goal is to modify the plan to bypass failures."
-          });
+          // A dry run reports the failure as-is rather than asking for a repaired plan
+          if (!options.dryRun) {
+            const reflectionResponse = await generateText({
+              modelType: "planning",
+              prompt,
+              systemPrompt: "You are a resilient execution engine. Your goal is to modify the plan to bypass failures."
+            });
 
-          // In a real implementation, we would parse this into PlanStep[]
-          // For now, we'll log it and transition to FAILED as a fallback if parsing fails,
-          // but we'll try to implement the state transition to REFLECTING as requested.
-          
-          console.log("Reflection response:", reflectionResponse.content);
+            // In a real implementation, we would parse this into PlanStep[]
+            // For now, we'll log it and transition to FAILED as a fallback if parsing fails,
+            // but we'll try to implement the state transition to REFLECTING as requested.
+            
+            console.log("Reflection response:", reflectionResponse.content);
+          }
           
           // Transition back to EXECUTING or FAILED based on reflection
           // For this implementation, we will treat reflection as a manual intervention point
@@ -654,12 +910,14 @@ Respond with only the updated steps for the remaining plan, ensuring dependencie
           // we'll fail if we can't automatically resolve.
           
           // Reverting to FAILED if no automatic replanning is implemented
+          state = await compensateCompletedSteps(plan, state, toolExecutor, options.traceCallback);
+
           const endTime = performance.now();
-          state = applyStateUpdate(state, {
+          state = traceStateChange(state, applyStateUpdate(state, {
             status: "FAILED",
             error: failedStepResult.error,
             completed_at: new Date().toISOString(),
-          });
+          }), options.traceCallback);
 
           if (options.persistState !== false) {
             await saveExecutionState(state);
@@ -690,23 +948,30 @@ Respond with only the updated steps for the remaining plan, ensuring dependencie
           console.error("Reflection failed:", reflectError);
         }
       }
+
+      // Steps retried in this round wait out the longest of their backoffs together,
+      // cut short when the time budget runs out first
+      if (retryBackoffMs > 0) {
+        await beforeDeadline(new Promise((resolve) => setTimeout(resolve, retryBackoffMs)), deadline);
+      }
     }
 
     const endTime = performance.now();
-    state = applyStateUpdate(state, {
+    state = traceStateChange(state, applyStateUpdate(state, {
       status: "COMPLETED",
       completed_at: new Date().toISOString(),
-    });
+    }), options.traceCallback);
 
     if (options.persistState !== false) {
       await saveExecutionState(state);
     }
 
-    // Generate final summary
-    const summary = await summarizeResults(plan, state);
-
-    // Score the outcome
-    await scoreOutcome(plan, state);
+    // Generate final summary and score the outcome (skipped for dry runs, which performed nothing)
+    let summary: string | undefined;
+    if (!options.dryRun) {
+      summary = await summarizeResults(plan, state);
+      await scoreOutcome(plan, state);
+    }
 
     return {
       state,
@@ -724,17 +989,27 @@ Respond with only the updated steps for the remaining plan, ensuring dependencie
       },
     };
   } catch (error) {
-    const endTime = performance.now();
-    const errorMessage = error instanceof Error ? error.message : String(error);
+    // Only roll back if the failure happened before all steps completed
+    if (state.status !== "COMPLETED") {
+      state = await compensateCompletedSteps(plan, state, toolExecutor, options.traceCallback);
+    }
 
-    state = applyStateUpdate(state, {
-      status: "FAILED",
+    const endTime = performance.now();
+    // Running out of time budget ends in TIMEOUT rather than FAILED
+    const timedOut = (error as { code?: string })?.code === "EXECUTION_TIMEOUT";
+    const errorCode: EngineErrorCode = timedOut ? "EXECUTION_TIMEOUT" : "UNKNOWN_ERROR";
+    const errorMessage = timedOut
+      ? (error as { message: string }).message
+      : error instanceof Error ? error.message : String(error);
+
+    state = traceStateChange(state, applyStateUpdate(state, {
+      status: timedOut ? "TIMEOUT" : "FAILED",
       error: {
-        code: "UNKNOWN_ERROR",
+        code: errorCode,
         message: errorMessage,
       },
       completed_at: new Date().toISOString(),
-    });
+    }), options.traceCallback);
 
     if (options.persistState !== false) {
       await saveExecutionState(state);
@@ -754,7 +1029,7 @@ Respond with only the updated steps for the remaining plan, ensuring dependencie
         cost_usd: state.token_usage.total_tokens * 0.0000001,
       },
       error: {
-        code: "UNKNOWN_ERROR",
+        code: errorCode,
         message: errorMessage,
       },
     };
@@ -772,6 +1047,7 @@ export async function resumeExecution(
   options: {
     traceCallback?: (entry: TraceEntry) => void;
     persistState?: boolean;
+    approvalToken?: string;
   } = {}
 ): Promise<ExecutionResult> {
   if (!state.plan) {
@@ -783,11 +1059,7 @@ export async function resumeExecution(
     });
   }
 
-  if (
-    state.status === "COMPLETED" ||
-    state.status === "FAILED" ||
-    state.status === "CANCELLED"
-  ) {
+  if (isTerminalStatus(state.status)) {
     return {
       state,
       success: state.status === "COMPLETED",
@@ -798,6 +1070,53 @@ export async function resumeExecution(
     };
   }
 
+  // A paused execution only continues with an unexpired, unused approval issued for the plan
+  // as persisted and for exactly the steps that are waiting on it
+  if (state.status === "AWAITING_CONFIRMATION") {
+    const pendingApproval = state.step_states
+      .filter((s) => s.status === "awaiting_confirmation")
+      .map((s) => s.step_id);
+
+    let approval: Awaited<ReturnType<typeof verifyApprovalToken>> = options.approvalToken
+      ? await verifyApprovalToken(options.approvalToken, state.plan, {
+          execution_id: state.execution_id,
+          step_ids: pendingApproval,
+        })
+      : { valid: false, reason: "Approval token required to resume a paused execution" };
+
+    // Consume the token so the same approval can't resume the execution twice
+    if (approval.valid) {
+      const ttlSeconds = Math.max(1, (approval.expires_at ?? 0) - Math.floor(Date.now() / 1000));
+      const firstUse = !!approval.jti && await getMemoryClient().acquireLock(`approval_token:${approval.jti}`, ttlSeconds);
+      if (!firstUse) {
+        approval = { valid: false, reason: "Approval token has already been used" };
+      }
+    }
+
+    if (!approval.valid) {
+      throw EngineErrorSchema.parse({
+        code: "APPROVAL_TOKEN_INVALID",
+        message: approval.reason || "Invalid approval token",
+        details: { execution_id: state.execution_id },
+        recoverable: true,
+        timestamp: new Date().toISOString(),
+      });
+    }
+
+    let approved = state;
+    for (const stepId of pendingApproval) {
+      approved = updateStepState(approved, stepId, { status: "pending" });
+    }
+
+    return executePlan(state.plan, toolExecutor, {
+      executionId: state.execution_id,
+      initialState: approved,
+      traceCallback: options.traceCallback,
+      persistState: options.persistState,
+      confirmedStepIds: pendingApproval,
+    });
+  }
+
   return executePlan(state.plan, toolExecutor, {
     executionId: state.execution_id,
     initialState: state,
@@ -806,6 +1125,60 @@ export async function resumeExecution(
   });
 }
 
+// ============================================================================
+// CANCEL EXECUTION
+// Abandon a persisted execution that is not actively running, undoing its completed steps
+// ============================================================================
+
+// Running executions own their state, so only paused or not-yet-started ones can be abandoned
+const CANCELLABLE_STATUSES: ExecutionStatus[] = ["RECEIVED", "PARSED", "PLANNED", "AWAITING_CONFIRMATION"];
+
+export async function cancelExecution(
+  state: ExecutionState,
+  toolExecutor: ToolExecutor,
+  options: {
+    traceCallback?: (entry: TraceEntry) => void;
+    persistState?: boolean;
+  } = {}
+): Promise<ExecutionResult> {
+  if (!CANCELLABLE_STATUSES.includes(state.status)) {
+    throw EngineErrorSchema.parse({
+      code: "STATE_TRANSITION_INVALID",
+      message: `Cannot cancel execution in ${state.status} state`,
+      details: { execution_id: state.execution_id, status: state.status },
+      recoverable: false,
+      timestamp: new Date().toISOString(),
+    });
+  }
+
+  // Steps that never ran are marked skipped so the record shows what was abandoned
+  let cancelled = state;
+  for (const step of state.step_states) {
+    if (step.status === "pending" || step.status === "awaiting_confirmation") {
+      cancelled = updateStepState(cancelled, step.step_id, { status: "skipped" });
+    }
+  }
+
+  // Undo what already ran, e.g. a hotel booked before the payment step paused for confirmation
+  if (cancelled.plan) {
+    cancelled = await compensateCompletedSteps(cancelled.plan, cancelled, toolExecutor, options.traceCallback);
+  }
+  cancelled = traceStateChange(state, transitionState(cancelled, "CANCELLED"), options.traceCallback);
+
+  if (options.persistState !== false) {
+    await saveExecutionState(cancelled);
+  }
+
+  return {
+    state: cancelled,
+    success: false,
+    completed_steps: getCompletedSteps(cancelled).length,
+    failed_steps: cancelled.step_states.filter((s) => s.status === "failed").length,
+    total_steps: cancelled.plan?.steps.length ?? 0,
+    execution_time_ms: cancelled.latency_ms,
+  };
+}
+
 // ============================================================================
 // EXECUTION ORCHESTRATOR CLASS
 // ============================================================================
@@ -878,9 +1251,23 @@ export class ExecutionOrchestrator {
     }
   }
 
-  async resume(state: ExecutionState): Promise<ExecutionResult> {
+  async resume(state: ExecutionState, approvalToken?: string): Promise<ExecutionResult> {
     return resumeExecution(state, this.toolExecutor, {
       traceCallback: this.traceCallback,
+      approvalToken,
+    });
+  }
+
+  async dryRun(plan: Plan): Promise<ExecutionResult> {
+    return executePlan(plan, this.toolExecutor, {
+      traceCallback: this.traceCallback,
+      dryRun: true,
+    });
+  }
+
+  async cancel(state: ExecutionState): Promise<ExecutionResult> {
+    return cancelExecution(state, this.toolExecutor, {
+      traceCallback: this.traceCallback,
     });
   }
 }
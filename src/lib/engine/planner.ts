@@ -640,3 +640,54 @@ export function getTopologicalOrder(plan: Plan): PlanStep[] {
 
   return result;
 }
+
+// ============================================================================
+// GET CRITICAL PATH DURATION
+// Estimates end-to-end plan duration with independent steps overlapping
+// ============================================================================
+
+export function getCriticalPathDuration(
+  plan: Plan,
+  durationOf: (step: PlanStep) => number = (step) => step.timeout_ms
+): number {
+  const finishTimes = new Map<string, number>();
+
+  // Topological order guarantees dependencies finish before their dependents start
+  for (const step of getTopologicalOrder(plan)) {
+    const startTime = Math.max(0, ...step.dependencies.map((depId) => finishTimes.get(depId) || 0));
+    finishTimes.set(step.id, startTime + durationOf(step));
+  }
+
+  return Math.max(0, ...Array.from(finishTimes.values()));
+}
+
+// ============================================================================
+// GET PLAN CAPABILITIES
+// Lists each distinct tool a plan will call, for review before approval
+// ============================================================================
+
+export interface PlanCapability {
+  tool_name: string;
+  requires_confirmation: boolean;
+  origin: string; // "local", an MCP server URL, or "unregistered" when the tool is unknown
+}
+
+export function getPlanCapabilities(
+  plan: Plan,
+  definitionOf: (toolName: string) => ToolDefinition | undefined
+): PlanCapability[] {
+  const capabilities = new Map<string, PlanCapability>();
+
+  for (const step of getTopologicalOrder(plan)) {
+    const definition = definitionOf(step.tool_name);
+    const existing = capabilities.get(step.tool_name);
+    capabilities.set(step.tool_name, {
+      tool_name: step.tool_name,
+      requires_confirmation:
+        !!existing?.requires_confirmation || step.requires_confirmation || !!definition?.requires_confirmation,
+      origin: definition ? definition.origin || "local" : "unregistered",
+    });
+  }
+
+  return Array.from(capabilities.values());
+}
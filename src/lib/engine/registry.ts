@@ -5,12 +5,31 @@ import { Tracer } from "./tracing";
 import { getMemoryClient } from "./memory";
 import { mcpConfig } from "../mcp-config";
 
+export interface ServerHealth {
+  server: string;
+  healthy: boolean;
+  latency_ms: number;
+  error?: string;
+}
+
+// Consecutive failures before a server's circuit opens
+const CIRCUIT_BREAKER_THRESHOLD = 3;
+// Seconds after the last failure before a single trial call is allowed (half-open)
+const CIRCUIT_BREAKER_COOLDOWN_SECONDS = 60;
+// Seconds a failure streak is remembered without further calls
+const CIRCUIT_BREAKER_MEMORY_SECONDS = 600;
+
+type CircuitState = "closed" | "open" | "half_open";
+// How a call was let through: as an ordinary call on a closed circuit, or as the half-open trial probe
+type CallAdmission = "closed" | "trial";
+
 /**
  * RegistryManager coordinates local and remote tool discovery.
  */
 export class RegistryManager {
   private localRegistry: ToolRegistry;
   private mcpClients: Map<string, MCPClient> = new Map();
+  private healthStatus: Map<string, boolean> = new Map();
 
   constructor() {
     this.localRegistry = getToolRegistry();
@@ -40,8 +59,6 @@ export class RegistryManager {
    * Discovers tools from all connected MCP servers and populates the local registry.
    */
   async discoverRemoteTools(): Promise<void> {
-    const memory = getMemoryClient();
-
     return Tracer.startActiveSpan("discover_remote_tools", async (span) => {
       const clients = Array.from(this.mcpClients.entries());
       for (const [name, client] of clients) {
@@ -55,12 +72,18 @@ export class RegistryManager {
             // Note: Registry.register already validates the ToolDefinition
             this.localRegistry.register(tool, async (params, context) => {
               return Tracer.startActiveSpan(`mcp_tool_call:${tool.name}`, async (toolSpan) => {
-                // 1. Check Circuit Breaker
-                const failCount = await memory.getCounter(serverKey);
-                if (failCount >= 3) {
+                // 1. Check Circuit Breaker and last known reachability
+                if (this.healthStatus.get(name) === false) {
+                  return {
+                    success: false,
+                    error: `MCP server ${name} is unreachable (last health probe failed).`,
+                  };
+                }
+                const admission = await this.admitCall(serverKey);
+                if (!admission) {
                   return {
                     success: false,
-                    error: `Circuit breaker tripped for MCP server: ${name}. Too many recent failures.`,
+                    error: `Circuit breaker tripped for MCP server: ${name}. Too many consecutive failures.`,
                   };
                 }
 
@@ -71,14 +94,17 @@ export class RegistryManager {
                     _trace_id: context.executionId
                   };
                   const result = await client.callTool(tool.name, paramsWithTrace, context.abortSignal);
+
+                  // 2. Any success closes the circuit
+                  await this.recordCallOutcome(serverKey, true, admission);
                   
                   return {
                     success: true,
                     output: result,
                   };
                 } catch (error: any) {
-                  // 2. Increment Failure Counter
-                  await memory.incrementCounter(serverKey, 60); // 60s window
+                  // 3. Extend the consecutive failure streak
+                  await this.recordCallOutcome(serverKey, false, admission);
                   
                   return {
                     success: false,
@@ -96,6 +122,124 @@ export class RegistryManager {
     });
   }
 
+  /**
+   * Derives a server's circuit state from its consecutive failure count and the time since the last failure.
+   */
+  async getCircuitState(serverKey: string): Promise<CircuitState> {
+    const memory = getMemoryClient();
+    const failCount = await memory.getCounter(serverKey);
+    if (failCount < CIRCUIT_BREAKER_THRESHOLD) {
+      return "closed";
+    }
+
+    try {
+      // The counter's expiry is refreshed on every failure, so elapsed TTL is the time since the last one
+      const remaining = await memory.getTtl(serverKey);
+      const sinceLastFailure = CIRCUIT_BREAKER_MEMORY_SECONDS - remaining;
+      return sinceLastFailure >= CIRCUIT_BREAKER_COOLDOWN_SECONDS ? "half_open" : "open";
+    } catch {
+      return "open";
+    }
+  }
+
+  /**
+   * Decides whether a call to a server may proceed, and how. A half-open circuit admits only the
+   * caller that wins the trial lock; the others are refused until the trial's outcome is recorded.
+   */
+  async admitCall(serverKey: string): Promise<CallAdmission | undefined> {
+    const state = await this.getCircuitState(serverKey);
+    if (state !== "half_open") {
+      return state === "closed" ? "closed" : undefined;
+    }
+    // The lock expires with the cooldown, so a trial call that never reports back doesn't block probing forever
+    const won = await getMemoryClient().acquireLock(`${serverKey}:trial`, CIRCUIT_BREAKER_COOLDOWN_SECONDS);
+    return won ? "trial" : undefined;
+  }
+
+  /**
+   * Records a call's outcome: success closes the circuit, failure extends the failure streak.
+   * Only the trial probe decides a half-open circuit and releases the trial lock; a call admitted
+   * while the circuit was closed that lands after it opened leaves the circuit as it is.
+   */
+  async recordCallOutcome(serverKey: string, success: boolean, admission: CallAdmission): Promise<void> {
+    const memory = getMemoryClient();
+    if (admission === "closed" && (await this.getCircuitState(serverKey)) !== "closed") {
+      return;
+    }
+
+    if (success) {
+      await memory.resetCounter(serverKey);
+    } else {
+      await memory.incrementCounter(serverKey, CIRCUIT_BREAKER_MEMORY_SECONDS);
+    }
+    if (admission === "trial") {
+      await memory.resetCounter(`${serverKey}:trial`);
+    }
+  }
+
+  /**
+   * Registers an additional MCP server by name.
+   */
+  registerServer(name: string, url: string): void {
+    this.mcpClients.set(name, new MCPClient(url));
+  }
+
+  /**
+   * Probes every MCP server with a lightweight HTTP GET and reports real reachability.
+   * Servers that do not respond within the timeout are reported as unhealthy.
+   */
+  async probeHealth(timeoutMs: number = 3000): Promise<ServerHealth[]> {
+    const clients = Array.from(this.mcpClients.entries());
+
+    return Promise.all(clients.map(async ([name, client]) => {
+      const startTime = performance.now();
+      const controller = new AbortController();
+      const timeoutId = setTimeout(() => controller.abort(), timeoutMs);
+
+      try {
+        const response = await fetch(client.getServerUrl(), {
+          method: "GET",
+          signal: controller.signal,
+        });
+        // SSE endpoints stream indefinitely; headers are enough to confirm reachability
+        await response.body?.cancel().catch(() => {});
+        return {
+          server: name,
+          healthy: response.ok,
+          latency_ms: Math.round(performance.now() - startTime),
+          error: response.ok ? undefined : `HTTP ${response.status}`,
+        };
+      } catch (error: any) {
+        return {
+          server: name,
+          healthy: false,
+          latency_ms: Math.round(performance.now() - startTime),
+          error: error.name === "AbortError" ? `Timed out after ${timeoutMs}ms` : error.message,
+        };
+      } finally {
+        clearTimeout(timeoutId);
+      }
+    }));
+  }
+
+  /**
+   * Probes all MCP servers and updates the cached availability used to fail fast on tool calls.
+   */
+  async refreshHealth(timeoutMs?: number): Promise<ServerHealth[]> {
+    const results = await this.probeHealth(timeoutMs);
+    for (const result of results) {
+      this.healthStatus.set(result.server, result.healthy);
+    }
+    return results;
+  }
+
+  /**
+   * Returns the cached availability from the last refresh. Servers never probed are omitted.
+   */
+  getCachedHealth(): Record<string, boolean> {
+    return Object.fromEntries(this.healthStatus);
+  }
+
   /**
    * Lists all available tools (local and discovered remote).
    */
@@ -1,4 +1,5 @@
 import { z } from "zod";
+import { IntentSchema } from "./types";
 
 /**
  * Recursive helper to map JSON Schema to Zod for deep validation.
@@ -15,8 +16,9 @@ export function mapJsonSchemaToZod(schema: Record<string, unknown>): z.ZodTypeAn
       }
       return z.string();
     case "number":
-    case "integer":
       return z.number();
+    case "integer":
+      return z.number().int();
     case "boolean":
       return z.boolean();
     case "array":
@@ -43,3 +45,84 @@ export function mapJsonSchemaToZod(schema: Record<string, unknown>): z.ZodTypeAn
       return z.any();
   }
 }
+
+const NUMBER_WORDS: Record<string, number> = {
+  a: 1, an: 1, one: 1, two: 2, three: 3, four: 4, five: 5, six: 6, seven: 7,
+  eight: 8, nine: 9, ten: 10, eleven: 11, twelve: 12, thirteen: 13, fourteen: 14,
+  fifteen: 15, sixteen: 16, seventeen: 17, eighteen: 18, nineteen: 19,
+  twenty: 20, thirty: 30, forty: 40, fifty: 50, sixty: 60, seventy: 70,
+  eighty: 80, ninety: 90,
+  couple: 2, pair: 2, dozen: 12,
+};
+
+/**
+ * Converts word-form numbers ("four", "a couple", "a dozen", "twenty one") to integers.
+ * Returns undefined if any word is not a recognized number word.
+ */
+export function wordsToNumber(text: string): number | undefined {
+  const words = text.toLowerCase().replace(/-/g, " ").split(/\s+/).filter((w) => w && w !== "of");
+  if (words.length === 0) return undefined;
+
+  let total = 0;
+  for (const word of words) {
+    const value = NUMBER_WORDS[word];
+    if (value === undefined) return undefined;
+
+    if (word === "dozen" || word === "couple" || word === "pair") {
+      total = Math.max(total, 1) * value; // "a dozen" -> 12, "two dozen" -> 24
+    } else if (value < 10 && total >= 20 && total % 10 === 0) {
+      total += value; // "twenty one" -> 21
+    } else if (total === 0) {
+      total = value;
+    } else {
+      return undefined; // e.g. "four five"
+    }
+  }
+
+  return total;
+}
+
+/**
+ * Coerces string-encoded scalars to the types declared by a JSON Schema.
+ * LLM planners frequently emit `"4"` or `"four"` for a numeric `party_size` or `"true"` for
+ * a boolean flag; these are converted before validation so that only genuinely
+ * malformed values (e.g. `party_size: "abc"`) are rejected.
+ */
+export function coerceParametersToSchema(
+  schema: Record<string, unknown>,
+  parameters: Record<string, unknown>
+): Record<string, unknown> {
+  const properties = (schema.properties as Record<string, Record<string, unknown>>) || {};
+  const coerced: Record<string, unknown> = { ...parameters };
+
+  for (const [key, value] of Object.entries(parameters)) {
+    const propDef = properties[key];
+    if (!propDef || typeof value !== "string") continue;
+
+    const trimmed = value.trim();
+    switch (propDef.type) {
+      case "number":
+      case "integer":
+        if (trimmed !== "" && !isNaN(Number(trimmed))) {
+          coerced[key] = Number(trimmed);
+        } else if (wordsToNumber(trimmed) !== undefined) {
+          coerced[key] = wordsToNumber(trimmed);
+        }
+        break;
+      case "boolean":
+        if (trimmed.toLowerCase() === "true") coerced[key] = true;
+        if (trimmed.toLowerCase() === "false") coerced[key] = false;
+        break;
+    }
+  }
+
+  return coerced;
+}
+
+/**
+ * JSON Schema document for the engine's Intent contract, for clients validating payloads in other languages.
+ * Uses the input shape, so fields with defaults are optional.
+ */
+export function getIntentJsonSchema(): Record<string, unknown> {
+  return z.toJSONSchema(IntentSchema, { io: "input" }) as Record<string, unknown>;
+}
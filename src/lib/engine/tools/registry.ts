@@ -21,7 +21,7 @@ import {
   EngineErrorSchema,
   EngineErrorCode,
 } from "../types";
-import { mapJsonSchemaToZod } from "../schema-utils";
+import { mapJsonSchemaToZod, coerceParametersToSchema } from "../schema-utils";
 import { getUserProfileProvider } from "../../context/user-profile";
 
 // ============================================================================
@@ -49,6 +49,8 @@ export interface ToolExecutionContext {
   timeoutMs: number;
   startTime: number;
   abortSignal?: AbortSignal;
+  // Validate and return a simulated result without invoking the tool
+  dryRun?: boolean;
 }
 
 // ============================================================================
@@ -68,6 +70,7 @@ interface RegisteredTool {
 
 export class ToolRegistry {
   private tools: Map<string, RegisteredTool> = new Map();
+  private callHistory: Map<string, number[]> = new Map();
 
   /**
    * Register a new tool
@@ -139,6 +142,53 @@ export class ToolRegistry {
     return this.list().filter((t) => t.category === category);
   }
 
+  /**
+   * List tools by domain tag
+   */
+  listByTag(tag: string): ToolDefinition[] {
+    const normalized = tag.toLowerCase();
+    return this.list().filter((t) => t.tags?.includes(normalized));
+  }
+
+  /**
+   * Check a tool's rate limits using a sliding window and record the call if allowed.
+   * When a limit is reached, returns how long to wait before the next call would be allowed.
+   * Pass record = false to check capacity without consuming it.
+   */
+  tryAcquire(name: string, now: number = Date.now(), record: boolean = true): { allowed: boolean; retry_after_ms?: number } {
+    const limits = this.getDefinition(name)?.rate_limits;
+    const windows = ([
+      [limits?.requests_per_minute, 60 * 1000],
+      [limits?.requests_per_hour, 60 * 60 * 1000],
+    ] as [number | undefined, number][]).filter((w): w is [number, number] => w[0] !== undefined);
+
+    if (windows.length === 0) {
+      return { allowed: true };
+    }
+
+    const longestWindow = Math.max(...windows.map(([, windowMs]) => windowMs));
+    const history = (this.callHistory.get(name) || []).filter((t) => now - t < longestWindow);
+    this.callHistory.set(name, history);
+
+    let retryAfterMs = 0;
+    for (const [limit, windowMs] of windows) {
+      const inWindow = history.filter((t) => now - t < windowMs);
+      if (inWindow.length >= limit) {
+        // The call that brings the window back under the limit must age out first
+        retryAfterMs = Math.max(retryAfterMs, inWindow[inWindow.length - limit] + windowMs - now);
+      }
+    }
+
+    if (retryAfterMs > 0) {
+      return { allowed: false, retry_after_ms: retryAfterMs };
+    }
+
+    if (record) {
+      history.push(now);
+    }
+    return { allowed: true };
+  }
+
   /**
    * Execute a tool with validation and timeout
    */
@@ -152,6 +202,7 @@ export class ToolRegistry {
     output?: unknown;
     error?: string;
     latency_ms: number;
+    retry_after_ms?: number;
   }> {
     const startTime = performance.now();
 
@@ -175,6 +226,9 @@ export class ToolRegistry {
         finalParameters = await getUserProfileProvider().hydrateParameters(finalParameters, requiredFields);
       }
 
+      // Coerce string-encoded scalars to their declared types before validation
+      finalParameters = coerceParametersToSchema(tool.definition.inputSchema, finalParameters);
+
       // Validate input parameters
       const validationResult = this.validateInput(tool.definition, finalParameters);
       if (!validationResult.valid) {
@@ -191,6 +245,28 @@ export class ToolRegistry {
         });
       }
 
+      // Enforce per-tool rate limits once the call is known to be valid, so rejected
+      // parameters (and the repair retries that follow them) don't use up the quota
+      const rateLimit = this.tryAcquire(name, Date.now(), !context.dryRun);
+      if (!rateLimit.allowed) {
+        throw EngineErrorSchema.parse({
+          code: "TOOL_RATE_LIMITED",
+          message: `Rate limit exceeded for ${name}; retry after ${rateLimit.retry_after_ms}ms`,
+          details: { tool: name, retry_after_ms: rateLimit.retry_after_ms },
+          recoverable: true,
+          timestamp: new Date().toISOString(),
+        });
+      }
+
+      // Dry runs stop here, reporting what would have been sent
+      if (context.dryRun) {
+        return {
+          success: true,
+          output: { dry_run: true, tool: name, parameters: finalParameters },
+          latency_ms: Math.round(performance.now() - startTime),
+        };
+      }
+
       // Execute with timeout
       const result = await this.executeWithTimeout(
         tool.implementation,
@@ -232,10 +308,13 @@ export class ToolRegistry {
 
       // If it's already an EngineError, pass it through
       if (error && typeof error === "object" && "code" in error && "message" in error) {
+        // Rate-limited calls tell the caller how long to wait before retrying
+        const retryAfterMs = (error as { details?: { retry_after_ms?: unknown } }).details?.retry_after_ms;
         return {
           success: false,
           error: String(error.message),
           latency_ms: latencyMs,
+          retry_after_ms: typeof retryAfterMs === "number" ? retryAfterMs : undefined,
         };
       }
 
@@ -368,7 +447,15 @@ export class ToolRegistry {
       abortSignal: signal
     };
 
-    return new Promise((resolve, reject) => {
+    // The caller's signal (e.g. the plan's time budget running out) aborts the tool as well
+    const onCallerAbort = () => abortController.abort();
+    if (context.abortSignal?.aborted) {
+      abortController.abort();
+    } else {
+      context.abortSignal?.addEventListener("abort", onCallerAbort, { once: true });
+    }
+
+    return new Promise<{ success: boolean; output?: unknown; error?: string }>((resolve, reject) => {
       const timeoutId = setTimeout(() => {
         abortController.abort();
         reject(
@@ -401,31 +488,35 @@ export class ToolRegistry {
             reject(error);
           }
         });
-    });
+    }).finally(() => context.abortSignal?.removeEventListener("abort", onCallerAbort));
   }
 
   /**
    * Create a ToolExecutor for use with ExecutionOrchestrator
    */
-  createToolExecutor(): {
+  createToolExecutor(options: { dryRun?: boolean } = {}): {
     execute: (
       toolName: string,
       parameters: Record<string, unknown>,
-      timeoutMs: number
+      timeoutMs: number,
+      signal?: AbortSignal
     ) => Promise<{
       success: boolean;
       output?: unknown;
       error?: string;
       latency_ms: number;
+      retry_after_ms?: number;
     }>;
   } {
     return {
-      execute: async (toolName, parameters, timeoutMs) => {
+      execute: async (toolName, parameters, timeoutMs, signal) => {
         return this.execute(toolName, parameters, {
           executionId: "unknown",
           stepId: "unknown",
           timeoutMs,
           startTime: performance.now(),
+          abortSignal: signal,
+          dryRun: options.dryRun,
         });
       },
     };
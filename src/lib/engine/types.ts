@@ -93,6 +93,11 @@ export const PlanStepSchema = z.object({
     max_attempts: z.number().int().positive().default(1),
     backoff_ms: z.number().int().nonnegative().default(1000),
   }).optional(),
+  // Undo action invoked if this step completed but the plan later fails
+  compensation: z.object({
+    tool_name: z.string(),
+    parameters: z.record(z.string(), z.unknown()).default({}),
+  }).optional(),
 });
 
 export type PlanStep = z.infer<typeof PlanStepSchema>;
@@ -220,6 +225,7 @@ export const ToolDefinitionSchema = z.object({
   timeout_ms: z.number().int().positive().default(30000),
   requires_confirmation: z.boolean().default(false),
   category: z.enum(["data", "action", "communication", "calculation", "external", "search"]),
+  tags: z.array(z.string()).optional(), // Lowercase domain labels for discovery (e.g., "transportation")
   origin: z.string().optional(), // Added for observability (e.g., MCP server URL)
   rate_limits: z.object({
     requests_per_minute: z.number().int().positive().optional(),
@@ -273,7 +279,7 @@ export type Checkpoint = z.infer<typeof CheckpointSchema>;
 
 export const StepExecutionStateSchema = z.object({
   step_id: z.string().uuid(),
-  status: z.enum(["pending", "in_progress", "completed", "failed", "skipped", "timeout", "awaiting_confirmation"]),
+  status: z.enum(["pending", "in_progress", "completed", "failed", "skipped", "timeout", "awaiting_confirmation", "compensated"]),
   input: z.record(z.string(), z.unknown()).optional(),
   output: z.unknown().optional(),
   error: z.object({
@@ -481,10 +487,13 @@ export const EngineErrorCodeSchema = z.enum([
   "PLAN_CIRCULAR_DEPENDENCY",
   "STEP_EXECUTION_FAILED",
   "STEP_TIMEOUT",
+  "EXECUTION_TIMEOUT",
   "TOOL_NOT_FOUND",
   "TOOL_EXECUTION_FAILED",
   "TOOL_VALIDATION_FAILED",
+  "TOOL_RATE_LIMITED",
   "STATE_TRANSITION_INVALID",
+  "APPROVAL_TOKEN_INVALID",
   "MEMORY_OPERATION_FAILED",
   "LLM_REQUEST_FAILED",
   "LLM_SCHEMA_VALIDATION_FAILED",
@@ -1,5 +1,6 @@
 import { Intent, Step } from "./schema";
 import { checkGuardrails } from "./guardrails";
+import { addIsoDuration } from "./date-utils";
 
 export interface ExecutionPlan {
   intent_id: string;
@@ -59,15 +60,25 @@ export function createExecutionPlan(intent: Intent, dryRun: boolean = true): Exe
       });
     }
 
+    // Derive the end time from an explicit duration when the intent doesn't carry one
+    let endTime = intent.parameters.end_time;
+    if (!endTime && intent.parameters.start_time && intent.parameters.duration) {
+      const start = new Date(intent.parameters.start_time);
+      if (!isNaN(start.getTime())) {
+        endTime = addIsoDuration(start, intent.parameters.duration)?.toISOString();
+      }
+    }
+
     steps.push({
       tool_name: "add_calendar_event",
       parameters: {
         events: [{
           title: intent.parameters.title || "New Event",
           start_time: intent.parameters.start_time || intent.parameters.temporal_expression,
-          end_time: intent.parameters.end_time,
+          end_time: endTime,
           location: intent.parameters.location || intent.parameters.restaurant_address,
-          restaurant_name: intent.parameters.restaurant_name
+          restaurant_name: intent.parameters.restaurant_name,
+          recurrence: intent.parameters.recurrence
         }]
       },
       requires_confirmation: guardrail.requiresConfirmation,
@@ -4,9 +4,9 @@ import { z } from "zod";
 import { env } from "./config";
 import { IntentSchema } from "./schema";
 import type { Intent } from "./schema";
-import { normalizeIntent } from "./normalization";
+import { normalizeIntent, isBlankInput } from "./normalization";
 import { resolveAmbiguity } from "./ambiguity";
-import type { IntentHypotheses } from "./ambiguity";
+import type { IntentHypotheses, AmbiguityThresholds } from "./ambiguity";
 
 const customOpenAI = createOpenAI({
   apiKey: env.LLM_API_KEY,
@@ -19,6 +19,26 @@ export interface IntentInferenceResult {
 }
 
 const CandidateSchema = IntentSchema.omit({ id: true, metadata: true, rawText: true });
+const CandidatesSchema = z.object({
+  candidates: z.array(CandidateSchema).min(1).max(3),
+});
+
+export interface InferIntentOptions {
+  // The user's IANA time zone; relative times like "tomorrow at 3pm" are resolved on their clock
+  timeZone?: string;
+  // Produces candidate interpretations of the text; defaults to the configured model
+  generateCandidates?: (system: string, prompt: string) => Promise<{ candidates: unknown[] }>;
+}
+
+async function generateCandidates(system: string, prompt: string): Promise<{ candidates: unknown[] }> {
+  const { object } = await generateObject({
+    model: customOpenAI(env.LLM_MODEL),
+    schema: CandidatesSchema,
+    system,
+    prompt,
+  });
+  return object;
+}
 
 /**
  * Infer intent from raw text with ambiguity detection.
@@ -26,9 +46,11 @@ const CandidateSchema = IntentSchema.omit({ id: true, metadata: true, rawText: t
 export async function inferIntent(
   text: string, 
   avoidTools: string[] = [],
-  history: Intent[] = []
+  history: Intent[] = [],
+  thresholds: Partial<AmbiguityThresholds> = {},
+  options: InferIntentOptions = {}
 ): Promise<IntentInferenceResult> {
-  if (!text || text.trim().length === 0) {
+  if (!text || isBlankInput(text)) {
     throw new Error("Input text is empty");
   }
 
@@ -42,12 +64,8 @@ ${history.map((h, i) => `${i+1}. TYPE: ${h.type} | PARAMS: ${JSON.stringify(h.pa
 Use this history to resolve pronouns ("it", "then", "there") or to understand the context of a follow-up request.`
     : "";
 
-  const { object } = await generateObject({
-    model: customOpenAI(env.LLM_MODEL),
-    schema: z.object({
-      candidates: z.array(CandidateSchema).min(1).max(3),
-    }),
-    system: `### ROLE: Semantic Normalization Architect
+  const generate = options.generateCandidates || generateCandidates;
+  const object = await generate(`### ROLE: Semantic Normalization Architect
 Objective: Eliminate LLM Jitter. Map user input to exactly ONE of the 6 core Intent Types with 100% repeatability.
 
 ### CORE ONTOLOGY
@@ -76,12 +94,10 @@ Objective: Eliminate LLM Jitter. Map user input to exactly ONE of the 6 core Int
 - Assign confidence based on semantic clarity and parameter completeness.
 - CONFIDENCE < 0.85: If the intent is ambiguous or critical parameters are missing, you MUST lower confidence below 0.85 to trigger a RESOLVE_AMBIGUITY event.
 
-SCHEMA_INVARIANCE: Only use the defined IntentType enum.${avoidToolsContext}`,
-    prompt: text,
-  });
+SCHEMA_INVARIANCE: Only use the defined IntentType enum.${avoidToolsContext}`, text);
 
-  const normalizedIntents = object.candidates.map(c => normalizeIntent(c, text, env.LLM_MODEL));
-  const hypotheses = resolveAmbiguity(normalizedIntents);
+  const normalizedIntents = object.candidates.map(c => normalizeIntent(c, text, env.LLM_MODEL, options.timeZone));
+  const hypotheses = resolveAmbiguity(normalizedIntents, thresholds);
 
   return {
     hypotheses,
@@ -4,14 +4,16 @@ import { normalizeIntent } from "./normalization";
 /**
  * Supersedes an existing intent with a new interpretation (e.g., after clarification).
  * This maintains the link to the parent for traceability.
+ * Relative times are resolved in the user's IANA time zone when given.
  */
 export function supersedeIntent(
   parentIntent: Intent,
   newRawText: string,
   newCandidate: any,
-  modelId: string
+  modelId: string,
+  timeZone?: string
 ): Intent {
-  const normalized = normalizeIntent(newCandidate, newRawText, modelId);
+  const normalized = normalizeIntent(newCandidate, newRawText, modelId, timeZone);
   
   return {
     ...normalized,
@@ -1,6 +1,25 @@
 import { IntentSchema } from "./schema";
 import type { Intent, IntentType } from "./schema";
-import { validateIntentParams, REQUIRED_FIELDS_MAP } from "./resolveAmbiguity";
+import { validateIntentParams, generateClarificationQuestion, REQUIRED_FIELDS_MAP } from "./resolveAmbiguity";
+import { resolveRelativeTime, extractDuration, extractRecurrence } from "./date-utils";
+
+// Ride endpoints and the confidence multiplier applied when each was defaulted.
+// An unknown destination makes the ride unusable; a defaulted pickup is usually the user's location.
+const RIDE_ENDPOINTS = [
+  { field: "destination_location", aliases: ["dropoff_location", "destination"], penalty: 0.6 },
+  { field: "pickup_location", aliases: ["origin"], penalty: 0.9 },
+];
+
+const PLACEHOLDER_LOCATION = /^(unknown( destination| location)?|current location|my location|tbd|n\/a|none|)$/i;
+
+// Whitespace and punctuation alone (e.g. "   ", "?", "...") can't express an intent.
+// Beyond ASCII only the no-break space and invisible format characters count as blank;
+// printable Latin-1 signs such as "ª", "½" or "£" are content.
+const BLANK_INPUT_PATTERN = /^[\s!-\/:-@\[-`{-~\u00A0\u00AD\u180E\u2000-\u206F\uFEFF\u3000-\u303F]*$/;
+
+export function isBlankInput(input: string): boolean {
+  return BLANK_INPUT_PATTERN.test(input);
+}
 
 /**
  * Normalizes a candidate intent from an LLM.
@@ -8,11 +27,13 @@ import { validateIntentParams, REQUIRED_FIELDS_MAP } from "./resolveAmbiguity";
  * 2. Cross-references with Ontology requirements.
  * 3. Adjusts confidence deterministically.
  * 4. Ensures Chain-of-Thought consistency.
+ * Relative times are resolved in the user's IANA time zone when given.
  */
 export function normalizeIntent(
   candidate: any,
   rawText: string,
-  modelId: string
+  modelId: string,
+  timeZone?: string
 ): Intent {
   // Pre-validation normalization to handle common LLM jitter
   const normalizedCandidate = { ...candidate };
@@ -70,7 +91,8 @@ export function normalizeIntent(
       confidence: 0.5,
       parameters: {
         ...intent.parameters,
-        missingFields
+        missingFields,
+        clarificationQuestion: generateClarificationQuestion(intent.type, intent.parameters, missingFields)
       },
       explanation: `Missing required fields: ${missingFields.join(", ")}`
     };
@@ -101,6 +123,31 @@ export function normalizeIntent(
        intent.parameters.action = action;
      }
 
+     // Resolve relative expressions ("tomorrow at 3pm") into a concrete start time
+     if (typeof intent.parameters.temporal_expression === 'string' && !intent.parameters.start_time) {
+       const resolved = resolveRelativeTime(intent.parameters.temporal_expression, new Date(), timeZone);
+       if (resolved) {
+         intent.parameters.start_time = resolved;
+       }
+     }
+
+     // Capture explicit durations ("for 2 hours") as ISO 8601 so an end time can be derived
+     if (!intent.parameters.duration) {
+       const duration = extractDuration(intent.parameters.temporal_expression || "") || extractDuration(rawText);
+       if (duration) {
+         intent.parameters.duration = duration;
+       }
+     }
+
+     // Repeating events ("every Monday at 10am") carry an RRULE; the start time is the first occurrence.
+     // The raw text only counts with explicit "every"/"each", so "the weekly report review" stays one-off.
+     if (!intent.parameters.recurrence) {
+       const recurrence = extractRecurrence(intent.parameters.temporal_expression || "") || extractRecurrence(rawText, true);
+       if (recurrence) {
+         intent.parameters.recurrence = recurrence;
+       }
+     }
+
      // Deep Semantic Validation: Check if the date is in the past
      if (intent.parameters.temporal_expression) {
        const date = new Date(intent.parameters.temporal_expression);
@@ -131,6 +178,21 @@ export function normalizeIntent(
         intent.explanation = (intent.explanation || "") + " [Confidence Note: Missing some specific target/amount details for transactional action]";
       }
     }
+
+    // Ride endpoints that are missing or filled with placeholders were defaulted, not understood
+    if (capability.includes("mobility") || capability.includes("ride")) {
+      const defaulted = RIDE_ENDPOINTS.filter(endpoint => {
+        const value = [endpoint.field, ...endpoint.aliases].map(f => args[f]).find(v => v !== undefined);
+        return typeof value !== "string" || PLACEHOLDER_LOCATION.test(value.trim());
+      });
+
+      for (const endpoint of defaulted) {
+        intent.confidence *= endpoint.penalty;
+      }
+      if (defaulted.length > 0) {
+        intent.explanation = (intent.explanation || "") + ` [Confidence Note: Defaulted ride fields: ${defaulted.map(e => e.field).join(", ")}]`;
+      }
+    }
   }
 
   return intent;
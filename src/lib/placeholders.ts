@@ -0,0 +1,76 @@
+/**
+ * Resolves `{{step_N.result...}}` placeholders in tool parameters.
+ *
+ * The planner references earlier step outputs with placeholders such as
+ * `{{step_0.result.lat}}` or `{{step_1.result[0].name}}`, where N is the
+ * 0-based step index. A parameter that consists solely of a placeholder is
+ * replaced by the referenced value as-is (preserving numbers and objects);
+ * placeholders embedded in longer strings are interpolated as text.
+ */
+
+const PLACEHOLDER_PATTERN = /\{\{\s*step_(\d+)\.result((?:\.[A-Za-z_$][\w$]*|\[\d+\])*)\s*\}\}/g;
+const WHOLE_PLACEHOLDER_PATTERN = /^\{\{\s*step_(\d+)\.result((?:\.[A-Za-z_$][\w$]*|\[\d+\])*)\s*\}\}$/;
+
+export interface PlaceholderResolution {
+  parameters: any;
+  unresolved: string[];
+}
+
+function lookupPath(value: unknown, path: string): unknown {
+  const segments = path.match(/[A-Za-z_$][\w$]*|\d+/g) || [];
+  let current: any = value;
+  for (const segment of segments) {
+    if (current === undefined || current === null) return undefined;
+    current = current[segment];
+  }
+  return current;
+}
+
+/**
+ * Replaces step-output placeholders using the outputs of previously executed steps,
+ * keyed by step index. Placeholders that cannot be resolved are left untouched and
+ * reported in `unresolved`.
+ */
+export function resolvePlaceholders(
+  parameters: any,
+  stepOutputs: Record<number, unknown>
+): PlaceholderResolution {
+  const unresolved: string[] = [];
+
+  const resolveValue = (value: any): any => {
+    if (typeof value === "string") {
+      const whole = value.match(WHOLE_PLACEHOLDER_PATTERN);
+      if (whole) {
+        const resolved = lookupPath(stepOutputs[parseInt(whole[1])], whole[2]);
+        if (resolved === undefined) {
+          unresolved.push(value);
+          return value;
+        }
+        return resolved;
+      }
+
+      return value.replace(PLACEHOLDER_PATTERN, (match, stepIndex: string, path: string) => {
+        const resolved = lookupPath(stepOutputs[parseInt(stepIndex)], path);
+        if (resolved === undefined) {
+          unresolved.push(match);
+          return match;
+        }
+        return typeof resolved === "object" ? JSON.stringify(resolved) : String(resolved);
+      });
+    }
+
+    if (Array.isArray(value)) {
+      return value.map(resolveValue);
+    }
+
+    if (value && typeof value === "object") {
+      return Object.fromEntries(
+        Object.entries(value).map(([key, v]) => [key, resolveValue(v)])
+      );
+    }
+
+    return value;
+  };
+
+  return { parameters: resolveValue(parameters), unresolved };
+}
@@ -35,3 +35,8 @@ export async function getUserPreferences(userId: string) {
   if (!redis) return null;
   return await redis.get(`prefs:${userId}`);
 }
+
+export async function deleteUserPreferences(userId: string): Promise<boolean> {
+  if (!redis) return false;
+  return (await redis.del(`prefs:${userId}`)) > 0;
+}
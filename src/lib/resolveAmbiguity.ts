@@ -24,3 +24,83 @@ export function validateIntentParams(intentType: string, params: Record<string,
     missingFields
   };
 }
+
+/**
+ * Clarification prompts per intent type, keyed by the missing required field.
+ */
+export const CLARIFICATION_QUESTIONS: Record<string, Record<string, string>> = {
+  SCHEDULE: {
+    action: "What would you like to schedule?",
+    temporal_expression: "When should this happen?",
+  },
+  SEARCH: {
+    query: "What are you looking for?",
+    scope: "Where should I search?",
+  },
+  ACTION: {
+    capability: "What would you like me to do?",
+    arguments: "Could you give me the details for this request?",
+  },
+  QUERY: {
+    target_object: "What would you like to know about?",
+  },
+  ANALYSIS: {
+    context: "What should I analyze?",
+  },
+};
+
+/**
+ * Argument prompts for transactional capabilities, ordered by importance.
+ * Capabilities are matched by substring, mirroring the transactional check in normalization.
+ */
+export const CAPABILITY_ARGUMENT_QUESTIONS: { match: string[]; fields: { field: string; aliases?: string[]; question: string }[] }[] = [
+  {
+    match: ["mobility", "ride"],
+    fields: [
+      { field: "destination_location", aliases: ["dropoff_location", "destination"], question: "Where should the ride take you?" },
+      { field: "pickup_location", aliases: ["origin"], question: "Where should I pick you up?" },
+    ],
+  },
+  {
+    match: ["booking", "reserve", "restaurant_table"],
+    fields: [
+      { field: "restaurant_name", question: "Which restaurant should I book?" },
+      { field: "party_size", question: "How many people?" },
+      { field: "date", question: "What day should I book it for?" },
+      { field: "time", question: "What time should I book it for?" },
+    ],
+  },
+];
+
+function isBlank(value: unknown): boolean {
+  return value === undefined || value === null || (typeof value === 'string' && value.trim() === '');
+}
+
+/**
+ * Produces an intent-specific clarification question for the most important missing detail.
+ * Falls back to undefined when nothing specific is missing.
+ */
+export function generateClarificationQuestion(
+  intentType: string,
+  params: Record<string, any>,
+  missingFields: string[] = validateIntentParams(intentType, params).missingFields
+): string | undefined {
+  // missingFields preserves REQUIRED_FIELDS_MAP order, so the first entry is the most important
+  if (missingFields.length > 0) {
+    const field = missingFields[0];
+    return CLARIFICATION_QUESTIONS[intentType]?.[field] || `Could you tell me the ${field.replace(/_/g, " ")}?`;
+  }
+
+  if (intentType === "ACTION" && typeof params.capability === 'string') {
+    const capability = params.capability.toLowerCase();
+    const args = (params.arguments && typeof params.arguments === 'object') ? params.arguments : {};
+    const spec = CAPABILITY_ARGUMENT_QUESTIONS.find(s => s.match.some(m => capability.includes(m)));
+
+    const missing = spec?.fields.find(f => [f.field, ...(f.aliases || [])].every(name => isBlank(args[name])));
+    if (missing) {
+      return missing.question;
+    }
+  }
+
+  return undefined;
+}
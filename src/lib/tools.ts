@@ -1,7 +1,7 @@
 import { ToolDefinition, ToolDefinitionMetadata, ExecuteToolResult } from "./tools/types";
 import { geocode_location, search_restaurant, GeocodeSchema, SearchRestaurantSchema } from "./tools/location_search";
 import { add_calendar_event } from "./tools/calendar";
-import { TOOLS, getTool, getToolsByCategory, getToolsRequiringConfirmation, listTools } from "./tools/registry";
+import { TOOLS, getTool, getToolsByCategory, getToolsByTag, getToolsRequiringConfirmation, listTools } from "./tools/registry";
 
 /**
  * Returns a string representation of all available tools for LLM prompting.
@@ -39,6 +39,7 @@ export {
   search_restaurant,
   getTool,
   getToolsByCategory,
+  getToolsByTag,
   getToolsRequiringConfirmation,
   listTools,
   TOOLS,
@@ -222,6 +222,7 @@ export const reserveRestaurantToolDefinition: ToolDefinitionMetadata = {
   timeout_ms: 30000,
   requires_confirmation: true,
   category: "action",
+  tags: ["reservation", "dining"],
   parameter_aliases: {
     "party size": "party_size",
     "booking time": "time"
@@ -6,11 +6,13 @@ export const EventItemSchema = z.object({
   end_time: z.string().describe("The end date and time. Use ISO 8601 format (e.g., '2026-02-10T21:00:00Z')."),
   location: z.string().optional().describe("Physical address or venue name for the event."),
   restaurant_name: z.string().optional().describe("If the event is at a restaurant, its name."),
-  restaurant_address: z.string().optional().describe("If the event is at a restaurant, its full address.")
+  restaurant_address: z.string().optional().describe("If the event is at a restaurant, its full address."),
+  recurrence: z.string().optional().describe("RFC 5545 RRULE for repeating events, without the 'RRULE:' prefix (e.g., 'FREQ=WEEKLY;BYDAY=MO').")
 });
 
 export const AddCalendarEventSchema = z.object({
-  events: z.array(EventItemSchema).min(1).describe("An array of one or more calendar events to schedule.")
+  events: z.array(EventItemSchema).min(1).describe("An array of one or more calendar events to schedule."),
+  timezone: z.string().optional().describe("The user's IANA time zone (e.g., 'America/New_York'), used to read relative start and end times.")
 });
 
 export async function add_calendar_event(params: z.infer<typeof AddCalendarEventSchema>) {
@@ -27,7 +29,7 @@ export async function add_calendar_event(params: z.infer<typeof AddCalendarEvent
     params = validated.data;
   }
 
-  const { events } = params;
+  const { events, timezone } = params;
   
   console.log(`Adding ${events.length} calendar event(s)...`);
   
@@ -36,6 +38,7 @@ export async function add_calendar_event(params: z.infer<typeof AddCalendarEvent
     start: e.start_time,
     end: e.end_time,
     location: e.location || e.restaurant_address || "",
+    recurrence: e.recurrence,
     description: (e.restaurant_name || e.restaurant_address)
       ? `Restaurant: ${e.restaurant_name || 'N/A'}
 Address: ${e.restaurant_address || 'N/A'}`
@@ -47,12 +50,13 @@ Address: ${e.restaurant_address || 'N/A'}`
     result: {
       status: "ready",
       count: events.length,
-      download_url: `/api/download-ics?events=${encodeURIComponent(serializedEvents)}`,
+      download_url: `/api/download-ics?events=${encodeURIComponent(serializedEvents)}${timezone ? `&timezone=${encodeURIComponent(timezone)}` : ""}`,
       events: events.map(e => ({
         title: e.title,
         start_time: e.start_time,
         end_time: e.end_time,
         location: e.location || e.restaurant_address || "",
+        recurrence: e.recurrence,
       }))
     }
   };
@@ -72,6 +72,7 @@ export const getLiveOperationalStateToolDefinition: ToolDefinitionMetadata = {
   timeout_ms: 10000,
   requires_confirmation: false,
   category: "data",
+  tags: ["dining"],
   rate_limits: {
     requests_per_minute: 30,
     requests_per_hour: 500
@@ -63,6 +63,7 @@ export const TOOLS: Map<string, ToolDefinition> = new Map([
     timeout_ms: 15000,
     requires_confirmation: false,
     category: "data",
+    tags: ["location"],
     responseSchema: z.object({
       lat: z.number(),
       lon: z.number()
@@ -104,6 +105,7 @@ export const TOOLS: Map<string, ToolDefinition> = new Map([
     timeout_ms: 30000,
     requires_confirmation: false,
     category: "data",
+    tags: ["dining", "location"],
     responseSchema: z.array(RestaurantResultSchema),
     execute: search_restaurant
   }],
@@ -117,6 +119,10 @@ export const TOOLS: Map<string, ToolDefinition> = new Map([
         events: {
           type: "array",
           description: "An array of one or more calendar events to schedule."
+        },
+        timezone: {
+          type: "string",
+          description: "The user's IANA time zone (e.g., 'America/New_York'), used to read relative start and end times."
         }
       },
       required: ["events"]
@@ -130,6 +136,7 @@ export const TOOLS: Map<string, ToolDefinition> = new Map([
     timeout_ms: 15000,
     requires_confirmation: false,
     category: "action",
+    tags: ["scheduling"],
     responseSchema: z.object({
       status: z.string(),
       count: z.number(),
@@ -173,6 +180,7 @@ export const TOOLS: Map<string, ToolDefinition> = new Map([
     timeout_ms: 30000,
     requires_confirmation: true,
     category: "external",
+    tags: ["transportation"],
     rate_limits: {
       requests_per_minute: 10,
       requests_per_hour: 100
@@ -207,6 +215,7 @@ export const TOOLS: Map<string, ToolDefinition> = new Map([
     timeout_ms: 15000,
     requires_confirmation: false,
     category: "external",
+    tags: ["transportation", "location"],
     rate_limits: {
       requests_per_minute: 60,
       requests_per_hour: 1000
@@ -239,6 +248,7 @@ export const TOOLS: Map<string, ToolDefinition> = new Map([
     timeout_ms: 30000,
     requires_confirmation: true,
     category: "action",
+    tags: ["reservation", "dining"],
     rate_limits: {
       requests_per_minute: 10,
       requests_per_hour: 100
@@ -266,6 +276,7 @@ export const TOOLS: Map<string, ToolDefinition> = new Map([
     timeout_ms: 30000,
     requires_confirmation: true,
     category: "communication",
+    tags: ["messaging"],
     rate_limits: {
       requests_per_minute: 60,
       requests_per_hour: 500
@@ -287,6 +298,7 @@ export const TOOLS: Map<string, ToolDefinition> = new Map([
     timeout_ms: 15000,
     requires_confirmation: false,
     category: "data",
+    tags: ["weather"],
     rate_limits: {
       requests_per_minute: 60,
       requests_per_hour: 1000
@@ -325,6 +337,14 @@ export function getToolsByCategory(category: string): ToolDefinition[] {
   return Array.from(TOOLS.values()).filter(tool => tool.category === category);
 }
 
+/**
+ * Gets all tools carrying a domain tag (e.g., "transportation", "reservation").
+ */
+export function getToolsByTag(tag: string): ToolDefinition[] {
+  const normalized = tag.toLowerCase();
+  return Array.from(TOOLS.values()).filter(tool => tool.tags?.includes(normalized));
+}
+
 /**
  * Gets all tools that require confirmation.
  */
@@ -44,6 +44,7 @@ export const storefrontTools: Record<string, ToolDefinition> = {
     timeout_ms: 30000,
     requires_confirmation: true,
     category: "action",
+    tags: ["ecommerce"],
     execute: create_product
   },
   update_product: {
@@ -65,6 +66,7 @@ export const storefrontTools: Record<string, ToolDefinition> = {
     timeout_ms: 30000,
     requires_confirmation: true,
     category: "action",
+    tags: ["ecommerce"],
     execute: update_product
   },
   delete_product: {
@@ -82,6 +84,7 @@ export const storefrontTools: Record<string, ToolDefinition> = {
     timeout_ms: 30000,
     requires_confirmation: true,
     category: "action",
+    tags: ["ecommerce"],
     execute: delete_product
   }
 };
@@ -0,0 +1,65 @@
+import { getUserAuditLogs, eraseUserAuditLogs } from "./audit";
+import { getUserPreferences, deleteUserPreferences } from "./preferences";
+import { validateRequest } from "./auth";
+import type { AuditLog } from "./types";
+
+/**
+ * Authenticates a data export or erasure request and resolves whose data it targets.
+ * Only authenticated callers may act, on the user named in x-user-id; the shared
+ * "anonymous" bucket belongs to no one and is never exported or erased.
+ */
+export async function authorizeUserDataRequest(req: Request): Promise<{ userId?: string; error?: string; status?: number }> {
+  const auth = await validateRequest(req);
+  if (auth.error) {
+    return { error: auth.error, status: auth.status };
+  }
+
+  const userId = req.headers.get("x-user-id")?.trim();
+  if (!userId || userId === "anonymous") {
+    return { error: "An authenticated user id is required", status: 400 };
+  }
+
+  return { userId };
+}
+
+export interface UserDataExport {
+  user_id: string;
+  exported_at: string;
+  preferences: unknown;
+  audit_logs: AuditLog[];
+}
+
+/**
+ * Collects everything stored about a user (preferences and audit history)
+ * into a single JSON-serializable document.
+ */
+export async function exportUserData(userId: string): Promise<UserDataExport> {
+  const [preferences, auditLogs] = await Promise.all([
+    getUserPreferences(userId),
+    // The per-user index covers every log still stored, so this is the full history
+    getUserAuditLogs(userId, Infinity),
+  ]);
+
+  return {
+    user_id: userId,
+    exported_at: new Date().toISOString(),
+    preferences: preferences || {},
+    audit_logs: auditLogs,
+  };
+}
+
+/**
+ * Erases all stored data for a user. Subsequent reads return empty preferences
+ * and no audit history.
+ */
+export async function eraseUserData(userId: string): Promise<{ preferences_deleted: boolean; audit_logs_deleted: number }> {
+  const [preferencesDeleted, auditLogsDeleted] = await Promise.all([
+    deleteUserPreferences(userId),
+    eraseUserAuditLogs(userId),
+  ]);
+
+  return {
+    preferences_deleted: preferencesDeleted,
+    audit_logs_deleted: auditLogsDeleted,
+  };
+}